@@ -1,78 +1,245 @@
-use std::fs::{self, DirEntry};
+use std::{
+    env,
+    fs::{self, DirEntry},
+};
 
 use proc_macro::TokenStream;
-use proc_macro2::TokenStream as TokenStream2;
-use quote::format_ident;
-use quote::quote;
-use syn::{parse_macro_input, DeriveInput, Item};
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse_macro_input, AttributeArgs, DeriveInput, ItemFn, Lit, Meta, MetaNameValue, NestedMeta,
+    Path,
+};
 
 extern crate proc_macro;
 
-fn has_part_2(path: &str) -> bool {
-    let contents = fs::read_to_string(path).unwrap();
-    let file = syn::parse_file(&contents).unwrap();
-    for item in &file.items {
-        match item {
-            Item::Fn(itemfn) => match itemfn.sig.ident.to_string().as_str() {
-                "part2" => {
-                    return true;
+/// The parser of a part that takes the parsed input.
+enum Parser {
+    Plain(Path),
+    /// A parser that returns a `Result`.
+    Fallible(Path),
+}
+
+/// The arguments of `#[aoc::solution(...)]`.
+struct Registration {
+    day: usize,
+    part: usize,
+    /// The name of the alternative implementation, or `None` for the main implementation of the part.
+    alternative: Option<String>,
+    parser: Option<Parser>,
+}
+
+fn parse_registration(args: &[NestedMeta]) -> Result<Registration, String> {
+    let mut day = None;
+    let mut part = None;
+    let mut alternative = None;
+    let mut parser = None;
+    for arg in args {
+        let NestedMeta::Meta(Meta::NameValue(MetaNameValue { path, lit, .. })) = arg else {
+            return Err("Expected arguments of the form `day = 1, part = 1`.".to_string());
+        };
+        let name = path
+            .get_ident()
+            .map(ToString::to_string)
+            .unwrap_or_default();
+        match (name.as_str(), lit) {
+            ("day", Lit::Int(lit)) => day = lit.base10_parse().ok(),
+            ("part", Lit::Int(lit)) => part = lit.base10_parse().ok(),
+            ("alternative", Lit::Str(lit)) => alternative = Some(lit.value()),
+            ("parse", Lit::Str(lit)) => {
+                parser = Some(Parser::Plain(
+                    lit.parse()
+                        .map_err(|err| format!("Invalid parser: {err}."))?,
+                ));
+            }
+            ("try_parse", Lit::Str(lit)) => {
+                parser = Some(Parser::Fallible(
+                    lit.parse()
+                        .map_err(|err| format!("Invalid parser: {err}."))?,
+                ));
+            }
+            _ => return Err(format!("Invalid argument '{name}'.")),
+        }
+    }
+    match (day, part) {
+        (Some(day @ 1..), Some(part @ 1..)) => Ok(Registration {
+            day,
+            part,
+            alternative,
+            parser,
+        }),
+        _ => Err("Both `day` and `part` must be given as positive integers.".to_string()),
+    }
+}
+
+/// The name of the constant that is defined for each registration, so that registering the same part twice in a file fails to compile. Registering a day in multiple files is reported by the runner instead.
+///
+/// Characters of the name of an alternative that can't be used in an identifier are replaced by their code, e.g. `a-b` becomes `a_2d_b`.
+fn get_marker(registration: &Registration) -> String {
+    let mut marker = format!(
+        "aoc_solution_day{:02}_part{}",
+        registration.day, registration.part
+    );
+    if let Some(alternative) = &registration.alternative {
+        marker += "_alternative_";
+        for c in alternative.chars() {
+            if c.is_ascii_alphanumeric() {
+                marker.push(c);
+            } else {
+                marker += &format!("_{:x}_", c as u32);
+            }
+        }
+    }
+    return marker;
+}
+
+/// Register a function as the implementation of a part of a day, e.g. `#[aoc::solution(day = 5, part = 1)]`.
+///
+/// Use `alternative = "name"` to register an alternative implementation of a part instead, which is cross-checked against the main implementation.
+///
+/// Use `parse = "parse_input"` for parts that take the parsed input (or `try_parse` if the parser returns a `Result`). The input is then parsed once and shared between the parts.
+#[proc_macro_attribute]
+pub fn solution(args: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args as AttributeArgs);
+    let itemfn = parse_macro_input!(item as ItemFn);
+    let registration = match parse_registration(&args) {
+        Ok(registration) => registration,
+        Err(err) => {
+            let error = syn::Error::new(Span::call_site(), err).to_compile_error();
+            return quote! {
+                #error
+                #itemfn
+            }
+            .into();
+        }
+    };
+
+    let ident = &itemfn.sig.ident;
+    let function = ident.to_string();
+    let Registration { day, part, .. } = registration;
+    let alternative = match &registration.alternative {
+        Some(name) => quote! { Some(#name) },
+        None => quote! { None },
+    };
+    let into_answer = quote! { ::aoc::runner::PartOutput::into_answer };
+    let implementation = match &registration.parser {
+        None => quote! {
+            ::aoc::solution::Implementation::Raw(|input| #into_answer(#ident(input)))
+        },
+        Some(Parser::Plain(parse) | Parser::Fallible(parse)) => {
+            let parser = parse.to_token_stream().to_string();
+            let (erase, downcast) = match &registration.parser {
+                Some(Parser::Fallible(_)) => {
+                    (quote! { erase_try_parsed }, quote! { downcast_try_parsed })
+                }
+                _ => (quote! { erase_parsed }, quote! { downcast_parsed }),
+            };
+            quote! {
+                ::aoc::solution::Implementation::Parsed {
+                    parser: #parser,
+                    parse: |input| ::aoc::solution::#erase(#parse(input)),
+                    part: |parsed| {
+                        // Binding this first makes the type of the parsed input come from the parser, rather than from the part (which may take e.g. a slice of it).
+                        let parsed = ::aoc::solution::#downcast(#parse, parsed);
+                        #into_answer(#ident(parsed))
+                    },
                 }
-                _ => {}
-            },
-            _ => {}
+            }
         }
+    };
+    let marker = format_ident!("{}", get_marker(&registration), span = ident.span());
+
+    return quote! {
+        #itemfn
+
+        // Defining this again in the same module is an error, which catches parts that are registered twice.
+        #[doc(hidden)]
+        #[allow(dead_code, non_upper_case_globals)]
+        const #marker: () = ();
+
+        ::aoc::inventory::submit! {
+            ::aoc::solution::Registration {
+                day: #day,
+                part: #part,
+                alternative: #alternative,
+                file: file!(),
+                function: #function,
+                implementation: #implementation,
+            }
+        }
+
+        // Marks the file as having registrations, which the aggregate runner checks. This is a glob import so that it can be repeated for every registration.
+        #[doc(hidden)]
+        #[allow(unused_imports)]
+        pub use ::aoc::solution::registered::*;
     }
-    return false;
+    .into();
 }
 
+/// Get the title of the puzzle from the first line of the file, which is a doc comment such as `//! Calorie Counting`.
+fn get_title(contents: &str) -> Option<String> {
+    let title = contents.lines().next()?.strip_prefix("//!")?.trim();
+    return (!title.is_empty()).then(|| title.to_string());
+}
+
+/// Get the name of the module for a file in `src/bin`, replacing characters that can't be used in an identifier.
+fn get_module_name(stem: &str) -> String {
+    let name: String = stem
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        return format!("_{name}");
+    }
+    return name;
+}
+
+/// List the days in the directory given by the `AOC_DAYS_DIR` environment variable, which is set by the build script of the runner. That also makes cargo rebuild the runner when a day is added.
 #[proc_macro_derive(RunnableListProvider)]
 pub fn part_finder_derive(input: TokenStream) -> TokenStream {
     let DeriveInput { ident, .. } = parse_macro_input!(input);
 
-    let mut uses: Vec<TokenStream2> = Vec::new();
-    let mut runnables: Vec<TokenStream2> = Vec::new();
+    let mut modules: Vec<TokenStream2> = Vec::new();
+    let mut titles: Vec<TokenStream2> = Vec::new();
 
-    let mut entries: Vec<DirEntry> = fs::read_dir("./src/bin")
-        .unwrap()
-        .into_iter()
-        .map(Result::unwrap)
-        .collect();
+    let dir = env::var("AOC_DAYS_DIR").expect("AOC_DAYS_DIR should be set by the build script.");
+    let mut entries: Vec<DirEntry> = fs::read_dir(dir).unwrap().map(Result::unwrap).collect();
     entries.sort_by_key(|e| e.file_name());
 
+    // Every file directly in `src/bin` is a day. Other binaries (such as the template) are in a directory of their own.
     for entry in entries {
         let fname = entry.file_name().into_string().unwrap();
-        if !fname.starts_with("day") || !fname.ends_with(".rs") {
-            continue; // Skip files that don't look like bins for days.
+        let Some(stem) = fname.strip_suffix(".rs") else {
+            continue;
+        };
+        if !entry.file_type().unwrap().is_file() {
+            continue;
         }
 
-        let modname = fname.replace(".rs", "");
-        let modident = format_ident!("{}", modname);
-        let has_part_2 = has_part_2(entry.path().to_str().unwrap());
+        // The module is included using its full path, which is then what `file!()` gives in the module. This is how the registrations are matched with their title.
+        let fullpath = entry.path().to_str().unwrap().to_string();
+        let contents = fs::read_to_string(&fullpath).unwrap();
+        if let Some(title) = get_title(&contents) {
+            titles.push(quote! { (#fullpath, #title) });
+        }
 
-        uses.push(quote! {
+        // The marker is imported by `#[aoc::solution(...)]`, so this fails to compile for days that don't register any parts.
+        let modident = format_ident!("{}", get_module_name(stem));
+        modules.push(quote! {
+            #[path = #fullpath]
             pub mod #modident;
+            const _: () = #modident::PARTS_ARE_REGISTERED_USING_AOC_SOLUTION;
         });
-
-        let part1ident =
-            quote! { Runnable::Implemented(|i| crate::bin::#modident::part1(&i).to_string()) };
-        let part2ident = if has_part_2 {
-            quote! { Runnable::Implemented(|i| crate::bin::#modident::part2(&i).to_string()) }
-        } else {
-            quote! { Runnable::Missing  }
-        };
-        runnables.push(quote! { (#modname, #part1ident, #part2ident) });
     }
 
     let output = quote! {
         mod bin {
             #![allow(dead_code)]
-            #(#uses)*
+            #(#modules)*
         }
         impl RunnableListProvider for #ident {
-            fn get() -> RunnableList {
-                return vec![
-                    #(#runnables),*
-                ];
+            fn get() -> Result<RunnableList, String> {
+                return ::aoc::solution::registered_solutions(&[#(#titles),*]);
             }
         }
     };
@@ -0,0 +1,11 @@
+use std::{env, fs, path::PathBuf};
+
+fn main() {
+    // The runner includes every file in this directory, so it has to be rebuilt when one is added. Cargo only knows about the files that are already included.
+    let dir: PathBuf = [&env::var("CARGO_MANIFEST_DIR").unwrap(), "..", "src", "bin"]
+        .iter()
+        .collect();
+    let dir = fs::canonicalize(dir).unwrap();
+    println!("cargo:rerun-if-changed={}", dir.display());
+    println!("cargo:rustc-env=AOC_DAYS_DIR={}", dir.display());
+}
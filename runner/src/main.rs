@@ -0,0 +1,513 @@
+use std::{env, fs, path::Path, process, time::Duration};
+
+use aoc::{
+    baseline::Baseline,
+    colour::{self, Cyan, Purple, Red},
+    filter::DayFilter,
+    pool,
+    report::{to_csv, to_json, to_junit, to_markdown, update_markdown_section, Record, Summary},
+    runner::{
+        get_input_paths, parse_duration, parse_percentage, print_runnable_run, save_solutions,
+        warn_if_timings_skewed, warn_unused_params, Input, PartRun, RunnableOptions, RunnableRunOk,
+        ThresholdOptions, Thresholds, REGRESSION_DEFAULT,
+    },
+    solution::{PartInfo, Solution},
+};
+use aoc_derive::RunnableListProvider;
+
+/// The solutions for all days.
+type RunnableList = Vec<Box<dyn Solution>>;
+pub trait RunnableListProvider {
+    fn get() -> Result<RunnableList, String>;
+}
+
+#[derive(RunnableListProvider)]
+pub struct ListProvider {}
+
+/// The file in which `--update-readme` replaces the results section.
+const README_PATH: &str = "README.md";
+
+#[derive(Clone, Copy, Default, Eq, PartialEq)]
+enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+    Junit,
+}
+
+struct Args {
+    jobs: usize,
+    days: Vec<DayFilter>,
+    part: Option<usize>,
+    skip_missing: bool,
+    format: Format,
+    baseline: Option<String>,
+    save_baseline: Option<String>,
+    regression: f64,
+    thresholds: ThresholdOptions,
+    update_readme: bool,
+    list: bool,
+    options: RunnableOptions,
+    /// Timeouts for specific days, along with the day as it was given on the command line.
+    timeout_overrides: Vec<(String, DayFilter, Duration)>,
+}
+impl Args {
+    fn parse(args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut result = Self {
+            jobs: 1,
+            days: Vec::new(),
+            part: None,
+            skip_missing: false,
+            format: Format::default(),
+            baseline: None,
+            save_baseline: None,
+            regression: REGRESSION_DEFAULT,
+            thresholds: ThresholdOptions::from_env(),
+            update_readme: false,
+            list: false,
+            options: RunnableOptions::default(),
+            timeout_overrides: Vec::new(),
+        };
+        let mut args = args.skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--timeout" => {
+                    let value = args.next().ok_or("Missing value for --timeout.")?;
+                    // Either a plain duration to set the default, or day=duration to override it for a single day.
+                    match value.split_once('=') {
+                        Some((day, timeout)) => {
+                            result.timeout_overrides.push((
+                                day.to_string(),
+                                DayFilter::parse(day)?,
+                                parse_duration(timeout)?,
+                            ));
+                        }
+                        None => result.options.timeout = Some(parse_duration(&value)?),
+                    }
+                }
+                "--jobs" => {
+                    let value = args.next().ok_or("Missing value for --jobs.")?;
+                    result.jobs = value
+                        .parse()
+                        .map_err(|_| format!("Invalid number of jobs '{value}'."))?;
+                }
+                "--part" => {
+                    let value = args.next().ok_or("Missing value for --part.")?;
+                    result.part = match value.parse() {
+                        Ok(0) | Err(_) => return Err(format!("Invalid part '{value}'.")),
+                        Ok(part) => Some(part),
+                    };
+                }
+                "--list" => {
+                    result.list = true;
+                }
+                "--skip-missing" => {
+                    result.skip_missing = true;
+                }
+                "--format" => {
+                    result.format = match args.next().as_deref() {
+                        Some("text") => Format::Text,
+                        Some("json") => Format::Json,
+                        Some("csv") => Format::Csv,
+                        Some("junit") => Format::Junit,
+                        Some(format) => return Err(format!("Unknown format '{format}'.")),
+                        None => return Err("Missing value for --format.".to_string()),
+                    };
+                }
+                "--baseline" => {
+                    result.baseline = Some(args.next().ok_or("Missing value for --baseline.")?);
+                }
+                "--save-baseline" => {
+                    result.save_baseline =
+                        Some(args.next().ok_or("Missing value for --save-baseline.")?);
+                }
+                "--regression" => {
+                    let value = args.next().ok_or("Missing value for --regression.")?;
+                    result.regression = parse_percentage(&value)?;
+                }
+                "--update-readme" => {
+                    result.update_readme = true;
+                }
+                _ => {
+                    if result.options.parse_arg(&arg, &mut args)?
+                        || result.thresholds.parse_arg(&arg, &mut args)?
+                    {
+                        continue;
+                    }
+                    if arg.starts_with('-') {
+                        return Err(format!("Unknown argument '{arg}'."));
+                    }
+                    result.days.push(DayFilter::parse(&arg)?);
+                }
+            }
+        }
+        // The table in the README is replaced as a whole, so it would lose the rows of the parts that were filtered out.
+        if result.update_readme
+            && (!result.days.is_empty() || result.part.is_some() || result.skip_missing)
+        {
+            return Err(
+                "--update-readme cannot be combined with filtering the days or parts.".to_string(),
+            );
+        }
+        Ok(result)
+    }
+
+    fn is_day_selected(&self, day: &str) -> bool {
+        self.days.is_empty() || self.days.iter().any(|filter| filter.matches(day))
+    }
+
+    fn is_part_selected(&self, part: &PartInfo) -> bool {
+        self.part.map_or(true, |p| p == part.number) && (part.implemented || !self.skip_missing)
+    }
+
+    /// Check that every day that a timeout is set for exists, as a typo would otherwise silently use the default.
+    fn check_timeout_overrides(&self, days: &[&str]) -> Result<(), String> {
+        for (day, filter, _) in &self.timeout_overrides {
+            if !days.iter().any(|name| filter.matches(name)) {
+                return Err(format!("--timeout {day}=... does not match any day."));
+            }
+        }
+        Ok(())
+    }
+
+    fn options_for(&self, day: &str) -> RunnableOptions {
+        RunnableOptions {
+            // Later overrides take precedence, like they would for a plain --timeout.
+            timeout: self
+                .timeout_overrides
+                .iter()
+                .rev()
+                .find(|(_, filter, _)| filter.matches(day))
+                .map(|(_, _, timeout)| *timeout)
+                .or(self.options.timeout),
+            ..self.options.clone()
+        }
+    }
+}
+
+/// The runs of a single input of a single day.
+struct DayRun {
+    /// The identifier of the day and input, e.g. `day15` for the main input and `day15.example` for an example.
+    key: String,
+    name: String,
+    input: Input,
+    parts: Vec<usize>,
+    runs: Result<Vec<PartRun>, String>,
+}
+
+fn get_successes(days: &[DayRun]) -> Vec<RunnableRunOk> {
+    days.iter()
+        .filter_map(|d| d.runs.as_ref().ok())
+        .flatten()
+        .filter_map(|r| r.run.clone().ok())
+        .collect()
+}
+
+/// Get the durations of the successful runs for the summary.
+///
+/// Parts of a day that parses its input share the time spent parsing, so it is only added to the first of them.
+fn get_durations(days: &[DayRun]) -> Vec<Duration> {
+    let mut durations = Vec::new();
+    for day in days {
+        let mut parse_duration = None;
+        for run in day
+            .runs
+            .iter()
+            .flatten()
+            .filter_map(|r| r.run.as_ref().ok())
+        {
+            let parse = match (run.parse_duration, parse_duration) {
+                (Some(duration), None) => {
+                    parse_duration = Some(duration);
+                    duration
+                }
+                _ => Duration::ZERO,
+            };
+            durations.push(run.duration + parse);
+        }
+    }
+    durations
+}
+
+fn print_text(days: &[DayRun], thresholds: &Thresholds, regression: f64) {
+    let successes = get_successes(days);
+    let summary = Summary::of(get_durations(days));
+
+    let thresholds = thresholds.for_average(summary.average, regression);
+    for day in days {
+        match &day.runs {
+            Ok(runs) => {
+                for run in runs {
+                    print_runnable_run(
+                        format!("{} part {}", day.name, run.part),
+                        run.run.clone(),
+                        &run.alternatives,
+                        &thresholds,
+                        false,
+                    );
+                }
+            }
+            Err(err) => {
+                println!("> {} failed: {}", Purple.paint(&day.name), Red.paint(err));
+            }
+        }
+    }
+    if !successes.is_empty() {
+        println!(
+            "Ran {} parts in {}, averaging {} per part.",
+            Cyan.paint(summary.count.to_string()),
+            Purple.paint(format!("{:?}", summary.total)),
+            Purple.paint(format!("{:?}", summary.average)),
+        );
+    }
+    let regressions = successes
+        .iter()
+        .filter(|r| r.baseline_change().is_some_and(|c| c > regression))
+        .count();
+    if regressions > 0 {
+        println!(
+            "{} parts regressed compared to the baseline.",
+            Red.paint(regressions.to_string()),
+        );
+    }
+}
+
+fn get_records(days: &[DayRun]) -> Vec<Record> {
+    let mut records = Vec::new();
+    for day in days {
+        match &day.runs {
+            Ok(runs) => {
+                for run in runs {
+                    let name = format!("{} part {}", day.name, run.part);
+                    let alternatives = Record::alternatives(&day.key, &name, run);
+                    records.push(Record::new(&day.key, name, run));
+                    records.extend(alternatives);
+                }
+            }
+            Err(err) => {
+                for part in &day.parts {
+                    let name = format!("{} part {part}", day.name);
+                    records.push(Record::failed(&day.key, *part, name, err));
+                }
+            }
+        }
+    }
+    records
+}
+
+/// Rewrite the results section of the README with a table of the results of the given runs.
+fn update_readme(days: &[DayRun]) -> Result<(), String> {
+    let summary = Summary::of(get_durations(days));
+    let table = to_markdown(&get_records(days), &summary);
+    let contents = fs::read_to_string(README_PATH)
+        .map_err(|err| format!("Unable to read '{README_PATH}': {err}."))?;
+    let contents = update_markdown_section(&contents, &table)
+        .map_err(|err| format!("Unable to update '{README_PATH}': {err}"))?;
+    fs::write(README_PATH, contents)
+        .map_err(|err| format!("Unable to write '{README_PATH}': {err}."))
+}
+
+fn print_list(solutions: &[Box<dyn Solution>]) {
+    for solution in solutions {
+        let parts: Vec<String> = solution
+            .parts()
+            .iter()
+            .map(|part| {
+                if !part.implemented {
+                    format!("{} (missing)", part.number)
+                } else if part.alternatives.is_empty() {
+                    part.number.to_string()
+                } else {
+                    format!(
+                        "{} (alternatives {})",
+                        part.number,
+                        part.alternatives.join(", ")
+                    )
+                }
+            })
+            .collect();
+        println!(
+            "{}: {} [parts {}]",
+            Purple.paint(solution.day()),
+            solution.title().unwrap_or("?"),
+            parts.join(", "),
+        );
+    }
+}
+
+fn run_selected_day(
+    args: &Args,
+    solution: &dyn Solution,
+    label: Option<String>,
+    path: String,
+    parts: Vec<usize>,
+) -> DayRun {
+    let day = solution.day();
+    let input = Input::File(path);
+    let runs = solution.run(&input, &parts, &args.options_for(day));
+    let name = day.replace("day", "Day ");
+    DayRun {
+        key: label
+            .as_ref()
+            .map_or(day.to_string(), |label| format!("{day}.{label}")),
+        name: label.map_or(name.clone(), |label| format!("{name} ({label})")),
+        input,
+        parts,
+        runs,
+    }
+}
+
+fn apply_baseline(days: &mut [DayRun], path: &str) -> Result<(), String> {
+    let baseline = Baseline::load(path)?;
+    for day in days {
+        for PartRun { part, run, .. } in day.runs.iter_mut().flatten() {
+            if let Ok(run) = run {
+                run.baseline = baseline.get(&day.key, *part);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn save_baseline(days: &[DayRun], path: &str) -> Result<(), String> {
+    // Update the existing baseline (if any) so that filtered runs don't discard the timings of the other parts.
+    let mut baseline = if Path::new(path).exists() {
+        Baseline::load(path)?
+    } else {
+        Baseline::default()
+    };
+    for day in days {
+        for PartRun { part, run, .. } in day.runs.iter().flatten() {
+            if let Ok(run) = run {
+                baseline.insert(&day.key, *part, run.duration);
+            }
+        }
+    }
+    baseline.save(path)
+}
+
+fn save_all_solutions(days: &[DayRun]) -> Result<(), String> {
+    for day in days {
+        let (Ok(runs), Some(path)) = (&day.runs, day.input.path()) else {
+            continue;
+        };
+        let runs: Vec<_> = runs.iter().map(|run| (run.part, &run.run)).collect();
+        save_solutions(path, &runs)?;
+    }
+    Ok(())
+}
+
+fn get_selected_solutions(args: &Args) -> Result<RunnableList, String> {
+    let solutions = ListProvider::get()?;
+    let days: Vec<_> = solutions.iter().map(|solution| solution.day()).collect();
+    args.check_timeout_overrides(&days)?;
+    Ok(solutions
+        .into_iter()
+        .filter(|solution| args.is_day_selected(solution.day()))
+        .collect())
+}
+
+fn main() {
+    colour::init();
+    let args = Args::parse(env::args()).unwrap_or_else(|err| {
+        eprintln!("{}", Red.paint_err(err));
+        process::exit(1);
+    });
+    let thresholds = args
+        .thresholds
+        .resolve(Thresholds::RELATIVE_DEFAULT)
+        .unwrap_or_else(|err| {
+            eprintln!("{}", Red.paint_err(err));
+            process::exit(1);
+        });
+
+    let solutions = get_selected_solutions(&args).unwrap_or_else(|err| {
+        eprintln!("{}", Red.paint_err(err));
+        process::exit(1);
+    });
+    if args.list {
+        print_list(&solutions);
+        return;
+    }
+
+    let runnables: Vec<_> = solutions
+        .iter()
+        .map(|solution| {
+            let parts: Vec<usize> = solution
+                .parts()
+                .iter()
+                .filter(|part| args.is_part_selected(part))
+                .map(|part| part.number)
+                .collect();
+            (solution.as_ref(), parts)
+        })
+        .filter(|(_, parts)| !parts.is_empty())
+        .collect();
+    let day_count = runnables.len();
+    let runnables: Vec<_> = runnables
+        .into_iter()
+        .flat_map(|(solution, parts)| {
+            get_input_paths(solution.day())
+                .into_iter()
+                .map(move |(label, path)| (solution, label, path, parts.clone()))
+        })
+        .collect();
+    let threads = pool::get_thread_count(args.jobs);
+    if args.format == Format::Text {
+        if threads > 1 {
+            // Parts compete for CPU time when running in parallel, so timings are less reliable.
+            println!(
+                "Running {} days using {} inputs on {} threads...",
+                Cyan.paint(day_count.to_string()),
+                Cyan.paint(runnables.len().to_string()),
+                Cyan.paint(threads.to_string())
+            );
+        } else {
+            println!(
+                "Running {} days using {} inputs...",
+                Cyan.paint(day_count.to_string()),
+                Cyan.paint(runnables.len().to_string())
+            );
+        }
+    }
+    let mut days = pool::map(runnables, threads, |(solution, label, path, parts)| {
+        run_selected_day(&args, solution, label, path, parts)
+    });
+
+    if let Some(path) = &args.baseline {
+        if let Err(err) = apply_baseline(&mut days, path) {
+            eprintln!("{}", Red.paint_err(err));
+            process::exit(1);
+        }
+    }
+    if let Some(path) = &args.save_baseline {
+        if let Err(err) = save_baseline(&days, path) {
+            eprintln!("{}", Red.paint_err(err));
+            process::exit(1);
+        }
+    }
+
+    match args.format {
+        Format::Text => print_text(&days, &thresholds, args.regression),
+        Format::Json => print!("{}", to_json(&get_records(&days))),
+        Format::Csv => print!("{}", to_csv(&get_records(&days))),
+        Format::Junit => print!("{}", to_junit(&get_records(&days))),
+    }
+    warn_if_timings_skewed();
+    warn_unused_params();
+
+    if args.update_readme {
+        if let Err(err) = update_readme(&days) {
+            eprintln!("{}", Red.paint_err(err));
+            process::exit(1);
+        }
+    }
+
+    if args.options.save {
+        if let Err(err) = save_all_solutions(&days) {
+            eprintln!("{}", Red.paint_err(err));
+            process::exit(1);
+        }
+    }
+}
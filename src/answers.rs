@@ -0,0 +1,262 @@
+use std::{collections::BTreeMap, fmt::Display, fs, path::Path};
+
+use serde::Deserialize;
+use toml_edit::DocumentMut;
+
+/// The name of the file containing the expected answers for all inputs in the same directory.
+pub const ANSWERS_FILENAME: &str = "answers.toml";
+
+/// Expected answers for a set of inputs, keyed by the name of the input file and the part.
+///
+/// This is stored as TOML, with a table per input file. Keys that aren't part numbers are parameters for the parts (see [`crate::runner::param`]):
+///
+/// ```toml
+/// ["day01.txt"]
+/// 1 = 24000
+/// 2 = "45000"
+///
+/// ["day15.example.txt"]
+/// row = 10
+///
+/// ["day10.txt"]
+/// 2 = """
+/// ##..##..
+/// ##..##.."""
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    answers: BTreeMap<(String, usize), String>,
+    params: BTreeMap<(String, String), String>,
+}
+impl Answers {
+    pub fn insert(&mut self, input: &str, part: usize, answer: String) {
+        self.answers.insert((input.to_string(), part), answer);
+    }
+
+    pub fn get(&self, input: &str, part: usize) -> Option<&String> {
+        self.answers.get(&(input.to_string(), part))
+    }
+
+    pub fn insert_param(&mut self, input: &str, name: &str, value: String) {
+        self.params
+            .insert((input.to_string(), name.to_string()), value);
+    }
+
+    /// Get the parameters for the given input.
+    pub fn get_params(&self, input: &str) -> BTreeMap<String, String> {
+        self.params
+            .iter()
+            .filter(|((params_input, _), _)| params_input == input)
+            .map(|((_, name), value)| (name.clone(), value.clone()))
+            .collect()
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let inputs: BTreeMap<String, BTreeMap<String, Value>> =
+            toml::from_str(contents).map_err(|err| err.to_string().trim_end().to_string())?;
+        let mut answers = Self::default();
+        for (input, values) in inputs {
+            for (key, value) in values {
+                let value = value.to_string();
+                match key.parse() {
+                    Ok(part) => answers.insert(&input, part, value),
+                    Err(_) => answers.insert_param(&input, &key, value),
+                }
+            }
+        }
+        Ok(answers)
+    }
+
+    pub fn load(path: &str) -> Result<Self, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|err| format!("Unable to read answers file '{path}': {err}."))?;
+        Self::parse(&contents)
+            .map_err(|err| format!("Unable to parse answers file '{path}': {err}"))
+    }
+}
+
+/// Write answers for an input to the answers file at the given path, creating it if it doesn't exist yet.
+///
+/// The file is edited in place, so that comments and the formatting of everything else are kept.
+pub fn save_answers(path: &str, input: &str, answers: &[(usize, String)]) -> Result<(), String> {
+    let contents = if Path::new(path).exists() {
+        fs::read_to_string(path)
+            .map_err(|err| format!("Unable to read answers file '{path}': {err}."))?
+    } else {
+        String::new()
+    };
+    let contents = update_answers(&contents, input, answers)
+        .map_err(|err| format!("Unable to update answers file '{path}': {err}"))?;
+    fs::write(path, contents)
+        .map_err(|err| format!("Unable to write answers file '{path}': {err}."))
+}
+
+fn update_answers(
+    contents: &str,
+    input: &str,
+    answers: &[(usize, String)],
+) -> Result<String, String> {
+    let mut document: DocumentMut = contents
+        .parse()
+        .map_err(|err: toml_edit::TomlError| err.to_string().trim_end().to_string())?;
+    let table = document
+        .entry(input)
+        .or_insert_with(toml_edit::table)
+        .as_table_like_mut()
+        .ok_or(format!("'{input}' is not a table."))?;
+    for (part, answer) in answers {
+        let value = match Value::from(answer.as_str()) {
+            Value::Integer(number) => toml_edit::value(number),
+            Value::String(value) => toml_edit::value(value),
+        };
+        table.insert(&part.to_string(), value);
+    }
+    Ok(document.to_string())
+}
+
+/// A value in the answers file. Answers that are integers are stored as such to keep the file readable, everything else as a string.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Value {
+    Integer(i64),
+    String(String),
+}
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        match value.parse::<i64>() {
+            // Don't change values such as `+5` or `007` by normalising them.
+            Ok(number) if number.to_string() == value => Value::Integer(number),
+            _ => Value::String(value.to_string()),
+        }
+    }
+}
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Integer(number) => write!(f, "{number}"),
+            Value::String(value) => f.write_str(value),
+        }
+    }
+}
+
+/// Get the path of the answers file that contains the answers for the given input file, and the key of the input in it.
+pub fn get_answers_path(input_path: &str) -> (String, String) {
+    let path = Path::new(input_path);
+    let name = path.file_name().map_or(input_path.to_string(), |name| {
+        name.to_string_lossy().to_string()
+    });
+    let answers = path
+        .parent()
+        .unwrap_or(Path::new(""))
+        .join(ANSWERS_FILENAME)
+        .to_string_lossy()
+        .to_string();
+    (answers, name)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let answers = Answers::parse(
+            r##"
+# Some comment.
+["day01.txt"]
+1 = 24000
+2 = "45000" # Trailing comment.
+
+[day10]
+width = 40
+2 = """
+##..
+#.\"#"""
+"##,
+        )
+        .unwrap();
+        assert_eq!(answers.get("day01.txt", 1), Some(&"24000".to_string()));
+        assert_eq!(answers.get("day01.txt", 2), Some(&"45000".to_string()));
+        assert_eq!(answers.get("day10", 1), None);
+        assert_eq!(answers.get("day10", 2), Some(&"##..\n#.\"#".to_string()));
+        assert_eq!(
+            answers.get_params("day10"),
+            BTreeMap::from([("width".to_string(), "40".to_string())])
+        );
+        assert_eq!(answers.get_params("day01.txt"), BTreeMap::new());
+    }
+
+    #[test]
+    fn update() {
+        let contents = [
+            "# Some comment.",
+            r#"["day01.txt"]"#,
+            "1 = 5 # Trailing comment.",
+            "row = 10",
+            "",
+        ]
+        .join("\n");
+        let answers = [
+            (2, "a \"quoted\" \\ value".to_string()),
+            (3, "line 1\nline \"2\"".to_string()),
+        ];
+        let updated = update_answers(&contents, "day01.txt", &answers).unwrap();
+        let updated = update_answers(&updated, "day02.txt", &[(1, "15".to_string())]).unwrap();
+        assert_eq!(
+            updated,
+            [
+                "# Some comment.",
+                r#"["day01.txt"]"#,
+                "1 = 5 # Trailing comment.",
+                "row = 10",
+                r#"2 = 'a "quoted" \ value'"#,
+                r#"3 = """"#,
+                "line 1",
+                r#"line "2"""""#,
+                "",
+                r#"["day02.txt"]"#,
+                "1 = 15",
+                "",
+            ]
+            .join("\n")
+        );
+
+        let parsed = Answers::parse(&updated).unwrap();
+        for (part, answer) in answers {
+            assert_eq!(parsed.get("day01.txt", part), Some(&answer));
+        }
+        assert_eq!(parsed.get("day02.txt", 1), Some(&"15".to_string()));
+        assert_eq!(
+            update_answers(
+                "[\"day01.txt\"]\n1 = 5\n",
+                "day01.txt",
+                &[(1, "6".to_string())]
+            ),
+            Ok("[\"day01.txt\"]\n1 = 6\n".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_invalid() {
+        let err = Answers::parse("1 = 5\n").unwrap_err();
+        assert!(err.starts_with("TOML parse error at line 1"), "{err}");
+        let err = Answers::parse("[day01]\n1 = \"5\n").unwrap_err();
+        assert!(err.starts_with("TOML parse error at line 2"), "{err}");
+        let err = Answers::parse("[day01]\n1 = true\n").unwrap_err();
+        assert!(err.starts_with("TOML parse error at line 2"), "{err}");
+    }
+
+    #[test]
+    fn answers_path() {
+        assert_eq!(
+            get_answers_path("inputs/day01.txt"),
+            ("inputs/answers.toml".to_string(), "day01.txt".to_string())
+        );
+        assert_eq!(
+            get_answers_path("day01.txt"),
+            ("answers.toml".to_string(), "day01.txt".to_string())
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use std::{collections::BTreeMap, fmt::Display, fs, time::Duration};
+
+/// Durations of earlier runs, used to detect parts that have become slower.
+#[derive(Debug, Default, PartialEq)]
+pub struct Baseline {
+    durations: BTreeMap<(String, usize), Duration>,
+}
+impl Baseline {
+    pub fn insert(&mut self, day: &str, part: usize, duration: Duration) {
+        self.durations.insert((day.to_string(), part), duration);
+    }
+
+    pub fn get(&self, day: &str, part: usize) -> Option<Duration> {
+        self.durations.get(&(day.to_string(), part)).copied()
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let mut baseline = Self::default();
+        for (i, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = || format!("Invalid baseline entry on line {}: '{line}'.", i + 1);
+            let [day, part, nanos]: [&str; 3] = line
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .try_into()
+                .map_err(|_| invalid())?;
+            let part = part.parse().map_err(|_| invalid())?;
+            let nanos = nanos.parse().map_err(|_| invalid())?;
+            baseline.insert(day, part, Duration::from_nanos(nanos));
+        }
+        Ok(baseline)
+    }
+
+    pub fn load(path: &str) -> Result<Self, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|err| format!("Unable to read baseline file '{path}': {err}."))?;
+        Self::parse(&contents)
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        fs::write(path, self.to_string())
+            .map_err(|err| format!("Unable to write baseline file '{path}': {err}."))
+    }
+}
+impl Display for Baseline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# day part duration_ns")?;
+        for ((day, part), duration) in &self.durations {
+            writeln!(f, "{day} {part} {}", duration.as_nanos())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let mut baseline = Baseline::default();
+        baseline.insert("day02", 1, Duration::from_micros(15));
+        baseline.insert("day01", 2, Duration::from_millis(3));
+        baseline.insert("day01", 1, Duration::from_nanos(800));
+
+        let serialized = baseline.to_string();
+        assert_eq!(
+            serialized,
+            "# day part duration_ns\nday01 1 800\nday01 2 3000000\nday02 1 15000\n"
+        );
+        assert_eq!(Baseline::parse(&serialized), Ok(baseline));
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert_eq!(
+            Baseline::parse("day01 1\n"),
+            Err("Invalid baseline entry on line 1: 'day01 1'.".to_string())
+        );
+        assert_eq!(
+            Baseline::parse("\nday01 one 100\n"),
+            Err("Invalid baseline entry on line 2: 'day01 one 100'.".to_string())
+        );
+    }
+}
@@ -1,4 +1,6 @@
-use aoc::{parse_number_list, runner::run};
+//! Calorie Counting
+
+use aoc::{parse_number_list, runner::run_registered};
 
 fn parse_input(input: &str) -> Vec<i32> {
     return input
@@ -8,11 +10,13 @@ fn parse_input(input: &str) -> Vec<i32> {
         .collect();
 }
 
+#[aoc::solution(day = 1, part = 1)]
 pub fn part1(input: &str) -> i32 {
     let data = parse_input(input);
     data.into_iter().max().unwrap()
 }
 
+#[aoc::solution(day = 1, part = 2)]
 pub fn part2(input: &str) -> i32 {
     let mut data = parse_input(input);
     data.sort_unstable_by(|a, b| b.cmp(a));
@@ -20,7 +24,7 @@ pub fn part2(input: &str) -> i32 {
 }
 
 fn main() {
-    run(part1, part2);
+    run_registered();
 }
 
 #[cfg(test)]
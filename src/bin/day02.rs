@@ -1,4 +1,6 @@
-use aoc::runner::run;
+//! Rock Paper Scissors
+
+use aoc::runner::run_registered;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 enum Shape {
@@ -83,18 +85,20 @@ fn get_score(rounds: &[Round]) -> u16 {
         .sum();
 }
 
+#[aoc::solution(day = 2, part = 1)]
 pub fn part1(input: &str) -> u16 {
     let rounds = parse_input_part1(input);
     get_score(&rounds)
 }
 
+#[aoc::solution(day = 2, part = 2)]
 pub fn part2(input: &str) -> u16 {
     let rounds = parse_input_part2(input);
     get_score(&rounds)
 }
 
 fn main() {
-    run(part1, part2);
+    run_registered();
 }
 
 #[cfg(test)]
@@ -1,6 +1,8 @@
+//! Rucksack Reorganization
+
 use std::collections::HashSet;
 
-use aoc::runner::run;
+use aoc::runner::run_registered;
 
 #[derive(Debug, Eq, PartialEq)]
 struct Rucksack(HashSet<char>, HashSet<char>);
@@ -29,6 +31,7 @@ fn parse_input(input: &str) -> Vec<Rucksack> {
         .collect();
 }
 
+#[aoc::solution(day = 3, part = 1)]
 pub fn part1(input: &str) -> u16 {
     let rucksacks = parse_input(input);
     let commonalities = rucksacks
@@ -37,6 +40,7 @@ pub fn part1(input: &str) -> u16 {
     commonalities.into_iter().copied().map(get_priority).sum()
 }
 
+#[aoc::solution(day = 3, part = 2)]
 pub fn part2(input: &str) -> u16 {
     let mut rucksacks = parse_input(input).into_iter();
     let mut sum = 0u16;
@@ -55,7 +59,7 @@ pub fn part2(input: &str) -> u16 {
 }
 
 fn main() {
-    run(part1, part2);
+    run_registered();
 }
 
 #[cfg(test)]
@@ -1,6 +1,8 @@
+//! Camp Cleanup
+
 use std::ops::RangeInclusive;
 
-use aoc::runner::run;
+use aoc::runner::run_registered;
 
 type Range = RangeInclusive<i16>;
 
@@ -45,24 +47,24 @@ fn parse_input(input: &str) -> Vec<(Range, Range)> {
         .collect();
 }
 
-pub fn part1(input: &str) -> usize {
-    let pairs = parse_input(input);
+#[aoc::solution(day = 4, part = 1, parse = "parse_input")]
+pub fn part1(pairs: &[(Range, Range)]) -> usize {
     pairs
-        .into_iter()
+        .iter()
         .filter(|(left, right)| range_is_subset_two_ways(left, right))
         .count()
 }
 
-pub fn part2(input: &str) -> usize {
-    let pairs = parse_input(input);
+#[aoc::solution(day = 4, part = 2, parse = "parse_input")]
+pub fn part2(pairs: &[(Range, Range)]) -> usize {
     pairs
-        .into_iter()
+        .iter()
         .filter(|(left, right)| ranges_overlap(left, right))
         .count()
 }
 
 fn main() {
-    run(part1, part2);
+    run_registered();
 }
 
 #[cfg(test)]
@@ -110,11 +112,11 @@ mod tests {
 
     #[test]
     fn example_part1() {
-        assert_eq!(part1(EXAMPLE_INPUT), 2);
+        assert_eq!(part1(&parse_input(EXAMPLE_INPUT)), 2);
     }
 
     #[test]
     fn example_part2() {
-        assert_eq!(part2(EXAMPLE_INPUT), 4);
+        assert_eq!(part2(&parse_input(EXAMPLE_INPUT)), 4);
     }
 }
@@ -1,6 +1,8 @@
+//! Supply Stacks
+
 use std::collections::VecDeque;
 
-use aoc::runner::run;
+use aoc::runner::run_registered;
 use derive_new::new;
 
 #[derive(Debug, Eq, PartialEq, new)]
@@ -80,6 +82,7 @@ fn do_moves_9001(mut stacks: Stacks, moves: Moves) -> Stacks {
     stacks
 }
 
+#[aoc::solution(day = 5, part = 1)]
 pub fn part1(input: &str) -> String {
     let (mut stacks, moves) = parse_input(input);
     stacks = do_moves_9000(stacks, moves);
@@ -90,6 +93,7 @@ pub fn part1(input: &str) -> String {
         .collect();
 }
 
+#[aoc::solution(day = 5, part = 2)]
 pub fn part2(input: &str) -> String {
     let (mut stacks, moves) = parse_input(input);
     stacks = do_moves_9001(stacks, moves);
@@ -101,7 +105,7 @@ pub fn part2(input: &str) -> String {
 }
 
 fn main() {
-    run(part1, part2);
+    run_registered();
 }
 
 #[cfg(test)]
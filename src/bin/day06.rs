@@ -1,6 +1,8 @@
+//! Tuning Trouble
+
 use std::collections::HashSet;
 
-use aoc::runner::run;
+use aoc::runner::run_registered;
 
 fn find_marker(sequence: &str, length: usize) -> usize {
     for i in 0..=(sequence.len() - length) {
@@ -16,16 +18,18 @@ fn find_marker(sequence: &str, length: usize) -> usize {
     panic!("Did not find marker.");
 }
 
+#[aoc::solution(day = 6, part = 1)]
 pub fn part1(input: &str) -> usize {
     find_marker(input, 4)
 }
 
+#[aoc::solution(day = 6, part = 2)]
 pub fn part2(input: &str) -> usize {
     find_marker(input, 14)
 }
 
 fn main() {
-    run(part1, part2);
+    run_registered();
 }
 
 #[cfg(test)]
@@ -1,6 +1,8 @@
+//! No Space Left On Device
+
 use std::{collections::HashMap, iter::Peekable, vec::IntoIter};
 
-use aoc::runner::run;
+use aoc::runner::run_registered;
 
 type Listing<'a> = HashMap<&'a str, Entry<'a>>;
 
@@ -9,7 +11,7 @@ enum Entry<'a> {
     File(usize),
     Dir(Listing<'a>),
 }
-impl<'a> Entry<'a> {
+impl Entry<'_> {
     fn size(&self) -> usize {
         match self {
             Entry::File(fsize) => *fsize,
@@ -63,7 +65,7 @@ fn parse_input_lines<'a>(
     }
 }
 
-fn parse_input<'a>(input: &'a str) -> Entry {
+fn parse_input<'a>(input: &'a str) -> Entry<'a> {
     let mut lines: Peekable<IntoIter<&'a str>> = input
         .trim()
         .split('\n')
@@ -87,6 +89,7 @@ fn get_dir_sizes(matches: &mut Vec<usize>, entry: &Entry) {
     };
 }
 
+#[aoc::solution(day = 7, part = 1)]
 pub fn part1(input: &str) -> usize {
     let root = parse_input(input);
     let mut sizes = vec![];
@@ -94,6 +97,7 @@ pub fn part1(input: &str) -> usize {
     sizes.into_iter().filter(|s| s <= &100_000).sum()
 }
 
+#[aoc::solution(day = 7, part = 2)]
 pub fn part2(input: &str) -> usize {
     let root = parse_input(input);
     let space_needed = 30_000_000 - (70_000_000 - root.size());
@@ -107,7 +111,7 @@ pub fn part2(input: &str) -> usize {
 }
 
 fn main() {
-    run(part1, part2);
+    run_registered();
 }
 
 #[cfg(test)]
@@ -1,8 +1,10 @@
+//! Treetop Tree House
+
 use std::collections::HashSet;
 
 use aoc::{
     grid::{Grid as BaseGrid, Point},
-    runner::run,
+    runner::run_registered,
 };
 
 type Grid = BaseGrid<u8>;
@@ -70,9 +72,8 @@ fn count_visible_from_treehouse(grid: &Grid, start: Point, offset: (isize, isize
     count
 }
 
-pub fn part1(input: &str) -> usize {
-    let grid = parse_input(input);
-
+#[aoc::solution(day = 8, part = 1, parse = "parse_input")]
+pub fn part1(grid: &Grid) -> usize {
     let mut visible = HashSet::new();
     visible.insert(Point::new(0, 0));
     visible.insert(Point::new(0, grid.height - 1));
@@ -82,40 +83,40 @@ pub fn part1(input: &str) -> usize {
     for x in 0..grid.width {
         let north = Point::new(x, 0);
         visible.insert(north);
-        find_visible_from_edge(&grid, &mut visible, north, (0, 1));
+        find_visible_from_edge(grid, &mut visible, north, (0, 1));
 
         let south = Point::new(x, grid.height - 1);
         visible.insert(south);
-        find_visible_from_edge(&grid, &mut visible, south, (0, -1));
+        find_visible_from_edge(grid, &mut visible, south, (0, -1));
     }
 
     for y in 0..grid.height {
         let west = Point::new(0, y);
         visible.insert(west);
-        find_visible_from_edge(&grid, &mut visible, west, (1, 0));
+        find_visible_from_edge(grid, &mut visible, west, (1, 0));
 
         let east = Point::new(grid.width - 1, y);
         visible.insert(east);
-        find_visible_from_edge(&grid, &mut visible, east, (-1, 0));
+        find_visible_from_edge(grid, &mut visible, east, (-1, 0));
     }
 
     visible.len()
 }
 
-pub fn part2(input: &str) -> usize {
-    let grid = parse_input(input);
+#[aoc::solution(day = 8, part = 2, parse = "parse_input")]
+pub fn part2(grid: &Grid) -> usize {
     return grid
         .by_cell()
         .map(|(point, _)| {
-            let mut score = count_visible_from_treehouse(&grid, point, (0, 1));
+            let mut score = count_visible_from_treehouse(grid, point, (0, 1));
             if score > 0 {
-                score *= count_visible_from_treehouse(&grid, point, (0, -1));
+                score *= count_visible_from_treehouse(grid, point, (0, -1));
             }
             if score > 0 {
-                score *= count_visible_from_treehouse(&grid, point, (1, 0));
+                score *= count_visible_from_treehouse(grid, point, (1, 0));
             }
             if score > 0 {
-                score *= count_visible_from_treehouse(&grid, point, (-1, 0));
+                score *= count_visible_from_treehouse(grid, point, (-1, 0));
             }
             score
         })
@@ -124,7 +125,7 @@ pub fn part2(input: &str) -> usize {
 }
 
 fn main() {
-    run(part1, part2);
+    run_registered();
 }
 
 #[cfg(test)]
@@ -157,11 +158,11 @@ mod tests {
 
     #[test]
     fn example_part1() {
-        assert_eq!(part1(EXAMPLE_INPUT), 21);
+        assert_eq!(part1(&parse_input(EXAMPLE_INPUT)), 21);
     }
 
     #[test]
     fn example_part2() {
-        assert_eq!(part2(EXAMPLE_INPUT), 8);
+        assert_eq!(part2(&parse_input(EXAMPLE_INPUT)), 8);
     }
 }
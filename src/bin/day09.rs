@@ -1,6 +1,8 @@
+//! Rope Bridge
+
 use std::collections::HashSet;
 
-use aoc::{grid::Point as BasePoint, runner::run};
+use aoc::{grid::Point as BasePoint, runner::run_registered};
 use derive_new::new;
 
 type Point = BasePoint<isize>;
@@ -15,7 +17,7 @@ impl Direction {
 }
 
 #[derive(new, Eq, PartialEq, Debug)]
-struct Move {
+pub struct Move {
     direction: Point,
     distance: usize,
 }
@@ -51,8 +53,8 @@ fn follow(follower: &Point, leader: &Point) -> Point {
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let moves = parse_input(input);
+#[aoc::solution(day = 9, part = 1, parse = "parse_input")]
+pub fn part1(moves: &[Move]) -> usize {
     let mut head = Point::new(0, 0);
     let mut tail = Point::new(0, 0);
     let mut visited = HashSet::<Point>::new();
@@ -67,8 +69,8 @@ pub fn part1(input: &str) -> usize {
     visited.len()
 }
 
-pub fn part2(input: &str) -> usize {
-    let moves = parse_input(input);
+#[aoc::solution(day = 9, part = 2, parse = "parse_input")]
+pub fn part2(moves: &[Move]) -> usize {
     let mut chain = [Point::new(0, 0); 10];
     let mut visited = HashSet::<Point>::new();
     visited.insert(chain[9]);
@@ -85,7 +87,7 @@ pub fn part2(input: &str) -> usize {
 }
 
 fn main() {
-    run(part1, part2);
+    run_registered();
 }
 
 #[cfg(test)]
@@ -133,11 +135,11 @@ mod tests {
 
     #[test]
     fn example_part1() {
-        assert_eq!(part1(EXAMPLE_INPUT_1), 13);
+        assert_eq!(part1(&parse_input(EXAMPLE_INPUT_1)), 13);
     }
 
     #[test]
     fn example_part2() {
-        assert_eq!(part2(EXAMPLE_INPUT_2), 36);
+        assert_eq!(part2(&parse_input(EXAMPLE_INPUT_2)), 36);
     }
 }
@@ -1,6 +1,8 @@
+//! Cathode-Ray Tube
+
 use std::convert::TryInto;
 
-use aoc::runner::run;
+use aoc::runner::run_registered;
 
 #[derive(Debug, Eq, PartialEq)]
 enum Instruction {
@@ -44,6 +46,7 @@ fn run_instructions(instructions: Vec<Instruction>, callback: &mut impl FnMut(us
     }
 }
 
+#[aoc::solution(day = 10, part = 1)]
 pub fn part1(input: &str) -> i16 {
     let instructions = parse_input(input);
     let mut signal: i16 = 0;
@@ -55,6 +58,7 @@ pub fn part1(input: &str) -> i16 {
     signal
 }
 
+#[aoc::solution(day = 10, part = 2)]
 pub fn part2(input: &str) -> String {
     let instructions = parse_input(input);
     let mut output = String::new();
@@ -73,7 +77,7 @@ pub fn part2(input: &str) -> String {
 }
 
 fn main() {
-    run(part1, part2);
+    run_registered();
 }
 
 #[cfg(test)]
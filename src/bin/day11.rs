@@ -1,6 +1,8 @@
-use aoc::runner::run;
+//! Monkey in the Middle
 
-#[derive(Debug, PartialEq, Eq)]
+use aoc::runner::run_registered;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
 enum Operation {
     Add(u64),
     Mul(u64),
@@ -16,75 +18,65 @@ impl Operation {
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
-struct Monkey {
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Monkey {
     items: Vec<u64>,
     operation: Operation,
     test: u64,
     targets: [usize; 2],
 }
 
-fn parse_input(input: &str) -> Vec<Monkey> {
-    return input
+fn expect_prefix<'a>(line: Option<&'a str>, prefix: &str) -> Result<&'a str, String> {
+    let line = line.ok_or_else(|| format!("Missing line starting with '{prefix}'."))?;
+    line.strip_prefix(prefix)
+        .ok_or_else(|| format!("Expected line starting with '{prefix}', got '{line}'."))
+}
+
+fn parse_number<T: std::str::FromStr>(value: &str) -> Result<T, String> {
+    value
         .trim()
-        .split("\n\n")
-        .map(|block| {
-            let mut lines = block.trim().split('\n').map(str::trim);
-            assert!(lines.next().unwrap().starts_with("Monkey"));
-
-            let mut parts = lines.next().unwrap().splitn(2, ':');
-            assert_eq!(parts.next().unwrap(), "Starting items");
-            let items = parts
-                .next()
-                .unwrap()
-                .split(',')
-                .map(str::trim)
-                .map(str::parse)
-                .map(Result::unwrap)
-                .collect();
-
-            let mut parts = lines.next().unwrap().splitn(2, '=');
-            assert_eq!(parts.next().unwrap(), "Operation: new ");
-            let parts: [&str; 3] = parts
-                .next()
-                .unwrap()
-                .trim()
-                .splitn(3, ' ')
-                .collect::<Vec<&str>>()
-                .try_into()
-                .unwrap();
-            let operation = match parts {
-                ["old", "+", rhs] => Operation::Add(rhs.parse().unwrap()),
-                ["old", "*", "old"] => Operation::Square,
-                ["old", "*", rhs] => Operation::Mul(rhs.parse().unwrap()),
-                _ => panic!("Invalid operation {parts:?}"),
-            };
-
-            let line = lines.next().unwrap().trim();
-            assert!(line.starts_with("Test: divisible by "));
-            let test = line[19..].parse().unwrap();
-
-            let line = lines.next().unwrap().trim();
-            assert!(line.starts_with("If true: throw to monkey "));
-            let target_true = line[25..].parse().unwrap();
-
-            let line = lines.next().unwrap().trim();
-            assert!(line.starts_with("If false: throw to monkey "));
-            let target_false = line[26..].parse().unwrap();
-
-            assert!(lines.next().is_none());
+        .parse()
+        .map_err(|_| format!("Invalid number '{value}'."))
+}
 
-            Monkey {
-                items,
-                operation,
-                test,
-                targets: [target_true, target_false],
-            }
-        })
-        .collect();
+fn parse_monkey(block: &str) -> Result<Monkey, String> {
+    let mut lines = block.trim().split('\n').map(str::trim);
+    expect_prefix(lines.next(), "Monkey")?;
+
+    let items = expect_prefix(lines.next(), "Starting items:")?
+        .split(',')
+        .map(parse_number)
+        .collect::<Result<_, _>>()?;
+
+    let operation = expect_prefix(lines.next(), "Operation: new = ")?;
+    let operation = match operation.split(' ').collect::<Vec<&str>>()[..] {
+        ["old", "+", rhs] => Operation::Add(parse_number(rhs)?),
+        ["old", "*", "old"] => Operation::Square,
+        ["old", "*", rhs] => Operation::Mul(parse_number(rhs)?),
+        _ => return Err(format!("Invalid operation '{operation}'.")),
+    };
+
+    let test = parse_number(expect_prefix(lines.next(), "Test: divisible by ")?)?;
+    let target_true = parse_number(expect_prefix(lines.next(), "If true: throw to monkey ")?)?;
+    let target_false = parse_number(expect_prefix(lines.next(), "If false: throw to monkey ")?)?;
+
+    if let Some(line) = lines.next() {
+        return Err(format!("Unexpected line '{line}'."));
+    }
+
+    Ok(Monkey {
+        items,
+        operation,
+        test,
+        targets: [target_true, target_false],
+    })
+}
+
+fn parse_input(input: &str) -> Result<Vec<Monkey>, String> {
+    return input.trim().split("\n\n").map(parse_monkey).collect();
 }
 
-fn do_round(monkeys: &mut Vec<Monkey>, counter: &mut [u64], therapy: &impl Fn(u64) -> u64) {
+fn do_round(monkeys: &mut [Monkey], counter: &mut [u64], therapy: &impl Fn(u64) -> u64) {
     let mut new_items: Vec<Vec<u64>> = (0..monkeys.len()).map(|_| Vec::new()).collect();
     for (i, monkey) in monkeys.iter_mut().enumerate() {
         new_items.push(Vec::new());
@@ -103,7 +95,7 @@ fn do_round(monkeys: &mut Vec<Monkey>, counter: &mut [u64], therapy: &impl Fn(u6
     }
 }
 
-fn monkey_business(monkeys: &mut Vec<Monkey>, rounds: usize, therapy: impl Fn(u64) -> u64) -> u64 {
+fn monkey_business(monkeys: &mut [Monkey], rounds: usize, therapy: impl Fn(u64) -> u64) -> u64 {
     let mut counter = vec![0; monkeys.len()];
     for _ in 0..rounds {
         do_round(monkeys, &mut counter, &therapy);
@@ -112,19 +104,26 @@ fn monkey_business(monkeys: &mut Vec<Monkey>, rounds: usize, therapy: impl Fn(u6
     counter.pop().unwrap() * counter.pop().unwrap()
 }
 
-pub fn part1(input: &str) -> u64 {
-    let mut monkeys = parse_input(input);
-    monkey_business(&mut monkeys, 20, |worry| worry / 3)
+#[aoc::solution(day = 11, part = 1, try_parse = "parse_input")]
+pub fn part1(monkeys: &[Monkey]) -> u64 {
+    monkey_business(&mut monkeys.to_vec(), 20, |worry| worry / 3)
 }
 
-pub fn part2(input: &str) -> u64 {
-    let mut monkeys = parse_input(input);
-    let modulo = monkeys.iter().map(|m| m.test).reduce(|l, r| l * r).unwrap();
-    monkey_business(&mut monkeys, 10_000, |worry| worry % modulo)
+#[aoc::solution(day = 11, part = 2, try_parse = "parse_input")]
+pub fn part2(monkeys: &[Monkey]) -> Result<u64, String> {
+    let mut monkeys = monkeys.to_vec();
+    let modulo = monkeys
+        .iter()
+        .map(|m| m.test)
+        .reduce(|l, r| l * r)
+        .ok_or("No monkeys.")?;
+    Ok(monkey_business(&mut monkeys, 10_000, |worry| {
+        worry % modulo
+    }))
 }
 
 fn main() {
-    run(part1, part2);
+    run_registered();
 }
 
 #[cfg(test)]
@@ -165,7 +164,7 @@ mod tests {
 
     #[test]
     fn example_parse() {
-        let actual = parse_input(EXAMPLE_INPUT);
+        let actual = parse_input(EXAMPLE_INPUT).unwrap();
         let expected = vec![
             Monkey {
                 items: vec![79, 98],
@@ -197,7 +196,7 @@ mod tests {
 
     #[test]
     fn example_do_round() {
-        let mut monkeys = parse_input(EXAMPLE_INPUT);
+        let mut monkeys = parse_input(EXAMPLE_INPUT).unwrap();
         let mut counter = vec![0, 0, 0, 0];
         let therapy = |worry| worry / 3;
 
@@ -282,11 +281,22 @@ mod tests {
 
     #[test]
     fn example_part1() {
-        assert_eq!(part1(EXAMPLE_INPUT), 10_605);
+        assert_eq!(part1(&parse_input(EXAMPLE_INPUT).unwrap()), 10_605);
     }
 
     #[test]
     fn example_part2() {
-        assert_eq!(part2(EXAMPLE_INPUT), 2_713_310_158);
+        assert_eq!(
+            part2(&parse_input(EXAMPLE_INPUT).unwrap()),
+            Ok(2_713_310_158)
+        );
+    }
+
+    #[test]
+    fn invalid_operation() {
+        assert_eq!(
+            parse_input(&EXAMPLE_INPUT.replace("old + 6", "old - 6")),
+            Err("Invalid operation 'old - 6'.".to_string())
+        );
     }
 }
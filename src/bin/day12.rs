@@ -1,11 +1,13 @@
+//! Hill Climbing Algorithm
+
 use std::{
     cmp::Ordering,
-    collections::{BinaryHeap, HashSet},
+    collections::{BinaryHeap, HashSet, VecDeque},
 };
 
 use aoc::{
     grid::{Grid as BaseGrid, Point},
-    runner::run,
+    runner::run_registered,
 };
 use derive_new::new;
 
@@ -50,7 +52,7 @@ struct PartialPath {
 // Sorting comparisons are inverted since we always want the smallest item from the max-heap.
 impl PartialOrd for PartialPath {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(other.steps.cmp(&self.steps))
+        Some(self.cmp(other))
     }
 }
 impl Ord for PartialPath {
@@ -88,28 +90,80 @@ fn pathfind(
     }
 }
 
-pub fn part1(input: &str) -> u16 {
-    let (grid, start, end) = parse_input(input);
+// Since every step has the same cost a plain breadth-first search finds the same result without the heap.
+fn pathfind_bfs(
+    grid: &Grid,
+    start: Point,
+    predicate_valid: fn(u8, u8) -> bool,
+    predicate_done: impl Fn(Point) -> bool,
+) -> u16 {
+    let mut visited: HashSet<Point> = HashSet::new();
+    let mut queue: VecDeque<(u16, Point)> = VecDeque::new();
+    visited.insert(start);
+    queue.push_back((0, start));
+    while let Some((steps, current)) = queue.pop_front() {
+        let current_height = *grid.getp(current).unwrap();
+        for point in grid.neighbours(current, false) {
+            if visited.contains(&point) {
+                continue;
+            }
+
+            let height = *grid.getp(point).unwrap();
+            if predicate_valid(height, current_height) {
+                if predicate_done(point) {
+                    return steps + 1;
+                }
+
+                visited.insert(point);
+                queue.push_back((steps + 1, point));
+            }
+        }
+    }
+    panic!("No path found.");
+}
+
+#[aoc::solution(day = 12, part = 1, parse = "parse_input")]
+pub fn part1((grid, start, end): &(Grid, Point, Point)) -> u16 {
     pathfind(
-        &grid,
-        start,
+        grid,
+        *start,
         |height, current| height <= current + 1,
-        |point| point == end,
+        |point| point == *end,
     )
 }
 
-pub fn part2(input: &str) -> u16 {
-    let (grid, _start, end) = parse_input(input);
+#[aoc::solution(day = 12, part = 2, parse = "parse_input")]
+pub fn part2((grid, _start, end): &(Grid, Point, Point)) -> u16 {
     pathfind(
-        &grid,
-        end,
+        grid,
+        *end,
+        |height, current| current <= height + 1,
+        |point| grid.getp(point).unwrap() == &0,
+    )
+}
+
+#[aoc::solution(day = 12, part = 1, alternative = "bfs", parse = "parse_input")]
+pub fn part1_bfs((grid, start, end): &(Grid, Point, Point)) -> u16 {
+    pathfind_bfs(
+        grid,
+        *start,
+        |height, current| height <= current + 1,
+        |point| point == *end,
+    )
+}
+
+#[aoc::solution(day = 12, part = 2, alternative = "bfs", parse = "parse_input")]
+pub fn part2_bfs((grid, _start, end): &(Grid, Point, Point)) -> u16 {
+    pathfind_bfs(
+        grid,
+        *end,
         |height, current| current <= height + 1,
         |point| grid.getp(point).unwrap() == &0,
     )
 }
 
 fn main() {
-    run(part1, part2);
+    run_registered();
 }
 
 #[cfg(test)]
@@ -145,11 +199,21 @@ mod tests {
 
     #[test]
     fn example_part1() {
-        assert_eq!(part1(EXAMPLE_INPUT), 31);
+        assert_eq!(part1(&parse_input(EXAMPLE_INPUT)), 31);
     }
 
     #[test]
     fn example_part2() {
-        assert_eq!(part2(EXAMPLE_INPUT), 29);
+        assert_eq!(part2(&parse_input(EXAMPLE_INPUT)), 29);
+    }
+
+    #[test]
+    fn example_part1_bfs() {
+        assert_eq!(part1_bfs(&parse_input(EXAMPLE_INPUT)), 31);
+    }
+
+    #[test]
+    fn example_part2_bfs() {
+        assert_eq!(part2_bfs(&parse_input(EXAMPLE_INPUT)), 29);
     }
 }
@@ -1,6 +1,8 @@
+//! Distress Signal
+
 use std::cmp::Ordering;
 
-use aoc::runner::run;
+use aoc::runner::run_registered;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 enum Item {
@@ -76,6 +78,7 @@ fn compare(left: &Item, right: &Item) -> Ordering {
     }
 }
 
+#[aoc::solution(day = 13, part = 1)]
 pub fn part1(input: &str) -> usize {
     let pairs = parse_input(input);
     let mut result = 0;
@@ -87,6 +90,7 @@ pub fn part1(input: &str) -> usize {
     result
 }
 
+#[aoc::solution(day = 13, part = 2)]
 pub fn part2(input: &str) -> usize {
     let mut packets: Vec<Item> = parse_input(input)
         .into_iter()
@@ -112,7 +116,7 @@ pub fn part2(input: &str) -> usize {
 }
 
 fn main() {
-    run(part1, part2);
+    run_registered();
 }
 
 #[cfg(test)]
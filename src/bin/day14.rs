@@ -1,6 +1,8 @@
+//! Regolith Reservoir
+
 use std::collections::HashSet;
 
-use aoc::{grid::Point as BasePoint, runner::run};
+use aoc::{grid::Point as BasePoint, runner::run_registered};
 
 type Point = BasePoint<isize>;
 
@@ -69,6 +71,7 @@ fn sand_fill(points: &mut Points, current: Point, void_start: isize) -> Sand {
     Sand::AtRest
 }
 
+#[aoc::solution(day = 14, part = 1)]
 pub fn part1(input: &str) -> usize {
     let mut points = parse_input(input);
     let void_start = points.iter().map(|p| p.y).max().unwrap();
@@ -80,6 +83,7 @@ pub fn part1(input: &str) -> usize {
     points.len() - size_start
 }
 
+#[aoc::solution(day = 14, part = 2)]
 pub fn part2(input: &str) -> usize {
     let mut points = parse_input(input);
     let floor = points.iter().map(|p| p.y).max().unwrap() + 2;
@@ -92,7 +96,7 @@ pub fn part2(input: &str) -> usize {
 }
 
 fn main() {
-    run(part1, part2);
+    run_registered();
 }
 
 #[cfg(test)]
@@ -1,11 +1,16 @@
+//! Beacon Exclusion Zone
+
 use std::{iter, ops::Range};
 
-use aoc::{grid::Point as BasePoint, runner::run};
+use aoc::{
+    grid::Point as BasePoint,
+    runner::{check_cancelled, param, run_registered},
+};
 
 type Point = BasePoint<isize>;
 
 #[derive(Debug, Eq, PartialEq)]
-struct Sensor {
+pub struct Sensor {
     point: Point,
     range: isize,
 }
@@ -71,7 +76,7 @@ fn ranges_overlap(left: &Range<isize>, right: &Range<isize>) -> bool {
         || right.contains(&left.end)
 }
 
-fn count_known_at_y(sensors: Vec<Sensor>, y: isize) -> usize {
+fn count_known_at_y(sensors: &[Sensor], y: isize) -> usize {
     let mut ranges: Vec<Range<isize>> = Vec::new();
     for sensor in sensors {
         let size = sensor.range - (sensor.point.y - y).abs();
@@ -105,13 +110,14 @@ fn count_known_at_y(sensors: Vec<Sensor>, y: isize) -> usize {
     ranges.into_iter().map(|r| r.len()).sum()
 }
 
-pub fn part1(input: &str) -> usize {
-    let sensors = parse_input(input);
-    count_known_at_y(sensors, 2_000_000)
+#[aoc::solution(day = 15, part = 1, parse = "parse_input")]
+pub fn part1(sensors: &[Sensor]) -> usize {
+    count_known_at_y(sensors, param("row", 2_000_000))
 }
 
-fn get_beacon(sensors: &Vec<Sensor>, range: isize) -> Point {
+fn get_beacon(sensors: &[Sensor], range: isize) -> Point {
     for sensor in sensors {
+        check_cancelled();
         // Consider all points that are _just_ outside the range of this sensor.
         for x in (sensor.point.x - sensor.range - 1).max(0)
             ..=(sensor.point.x + sensor.range + 1).min(range)
@@ -137,14 +143,14 @@ fn get_beacon(sensors: &Vec<Sensor>, range: isize) -> Point {
     Point::new(0, 0)
 }
 
-pub fn part2(input: &str) -> isize {
-    let sensors = parse_input(input);
-    let point = get_beacon(&sensors, 4_000_000);
+#[aoc::solution(day = 15, part = 2, parse = "parse_input")]
+pub fn part2(sensors: &[Sensor]) -> isize {
+    let point = get_beacon(sensors, param("range", 4_000_000));
     point.x * 4_000_000 + point.y
 }
 
 fn main() {
-    run(part1, part2);
+    run_registered();
 }
 
 #[cfg(test)]
@@ -237,7 +243,7 @@ mod tests {
     #[test]
     fn example_count_known_at_y() {
         let sensors = parse_input(EXAMPLE_INPUT);
-        assert_eq!(count_known_at_y(sensors, 10), 26);
+        assert_eq!(count_known_at_y(&sensors, 10), 26);
     }
 
     #[test]
@@ -1,6 +1,8 @@
+//! Proboscidea Volcanium
+
 use std::collections::{BinaryHeap, HashMap, HashSet};
 
-use aoc::runner::run;
+use aoc::runner::{check_cancelled, run_registered};
 
 #[derive(Debug, Eq, PartialEq)]
 struct Valve<'a> {
@@ -10,7 +12,7 @@ struct Valve<'a> {
 
 type Valves<'a> = HashMap<&'a str, Valve<'a>>;
 
-fn parse_input(input: &str) -> Valves {
+fn parse_input(input: &str) -> Valves<'_> {
     return input
         .trim()
         .split('\n')
@@ -105,7 +107,7 @@ struct State<'a, const C: usize> {
     flow: u16,
     total: u16,
 }
-impl<'a, const C: usize> State<'a, C> {
+impl<const C: usize> State<'_, C> {
     pub fn is_done(&self) -> bool {
         self.closed.is_empty()
     }
@@ -138,6 +140,8 @@ fn run_cycle_single_actor<'a, const C: usize>(
     idx: usize,
 ) {
     if idx >= C {
+        // Once per cycle rather than for every actor, as this is the hot path of the search.
+        check_cancelled();
         state.cycles -= 1;
         run_cycle_single_actor(global_state, state, 0);
         return;
@@ -255,19 +259,21 @@ fn dump_as_dot(valves: &Valves) {
     writeln!(&mut file, "}}").unwrap();
 }
 
+#[aoc::solution(day = 16, part = 1)]
 pub fn part1(input: &str) -> u16 {
     let valves = parse_input(input);
     // dump_as_dot(&valves);
     run_cycles::<1>(&valves, 30)
 }
 
+#[aoc::solution(day = 16, part = 2)]
 pub fn part2(input: &str) -> u16 {
     let valves = parse_input(input);
     run_cycles::<2>(&valves, 26)
 }
 
 fn main() {
-    run(part1, part2);
+    run_registered();
 }
 
 #[cfg(test)]
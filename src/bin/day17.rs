@@ -1,6 +1,11 @@
+//! Pyroclastic Flow
+
 use std::collections::HashSet;
 
-use aoc::{grid::Point, runner::run};
+use aoc::{
+    grid::Point,
+    runner::{check_cancelled, param, run_registered},
+};
 
 #[derive(Clone, Debug)]
 struct Stone {
@@ -217,6 +222,7 @@ fn simulate(input: &str, cycles: usize) -> (usize, HashSet<Point>) {
     let mut loop_size = 0;
     let mut change_per_loop = 0;
     'findloop: while finished < cycles {
+        check_cancelled();
         let drops = usize::min(cycles - finished, stones.len());
         finished += drops;
         let new_top = do_drops(top, &mut points, &mut moveloop, &mut stoneloop, drops);
@@ -255,16 +261,18 @@ fn simulate(input: &str, cycles: usize) -> (usize, HashSet<Point>) {
     (top, points)
 }
 
+#[aoc::solution(day = 17, part = 1)]
 pub fn part1(input: &str) -> usize {
-    simulate(input, 2_022).0
+    simulate(input, param("drops", 2_022)).0
 }
 
+#[aoc::solution(day = 17, part = 2)]
 pub fn part2(input: &str) -> usize {
     simulate(input, 1_000_000_000_000).0
 }
 
 fn main() {
-    run(part1, part2);
+    run_registered();
 }
 
 #[cfg(test)]
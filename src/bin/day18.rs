@@ -1,12 +1,14 @@
+//! Boiling Boulders
+
 use std::{
     cmp::Ordering,
     collections::{BinaryHeap, HashMap, HashSet},
 };
 
-use aoc::{counter::Counter, runner::run};
+use aoc::{counter::Counter, runner::run_registered};
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-struct Point(i8, i8, i8);
+pub struct Point(i8, i8, i8);
 impl Point {
     pub fn neighbours(&self) -> [Self; 6] {
         [
@@ -89,20 +91,20 @@ fn get_counts(points: &[Point]) -> HashMap<Point, u16> {
     neighbour_counts
 }
 
-pub fn part1(input: &str) -> u16 {
-    let points = parse_input(input);
-    let neighbour_counts = get_counts(&points);
+#[aoc::solution(day = 18, part = 1, parse = "parse_input")]
+pub fn part1(points: &[Point]) -> u16 {
+    let neighbour_counts = get_counts(points);
     neighbour_counts.into_values().sum()
 }
 
-pub fn part2(input: &str) -> u16 {
-    let points = parse_input(input);
-    let mut neighbour_counts = get_counts(&points);
+#[aoc::solution(day = 18, part = 2, parse = "parse_input")]
+pub fn part2(points: &[Point]) -> u16 {
+    let mut neighbour_counts = get_counts(points);
     let mut cooling = 0;
     while !neighbour_counts.is_empty() {
         let point = neighbour_counts.keys().next().unwrap().clone();
         let count = neighbour_counts.remove(&point).unwrap();
-        match check_air(&point, &points) {
+        match check_air(&point, points) {
             Air::Cooling(air_points) => {
                 cooling += count;
                 for air_point in air_points {
@@ -120,7 +122,7 @@ pub fn part2(input: &str) -> u16 {
 }
 
 fn main() {
-    run(part1, part2);
+    run_registered();
 }
 
 #[cfg(test)]
@@ -168,11 +170,11 @@ mod tests {
 
     #[test]
     fn example_part1() {
-        assert_eq!(part1(EXAMPLE_INPUT), 64);
+        assert_eq!(part1(&parse_input(EXAMPLE_INPUT)), 64);
     }
 
     #[test]
     fn example_part2() {
-        assert_eq!(part2(EXAMPLE_INPUT), 58);
+        assert_eq!(part2(&parse_input(EXAMPLE_INPUT)), 58);
     }
 }
@@ -1,6 +1,8 @@
+//! Not Enough Minerals
+
 use std::ops::{AddAssign, SubAssign};
 
-use aoc::runner::run;
+use aoc::runner::{check_cancelled, run_registered};
 
 #[derive(Debug, Eq, PartialEq)]
 struct Cost {
@@ -10,7 +12,7 @@ struct Cost {
 }
 
 #[derive(Debug, Eq, PartialEq)]
-struct Blueprint {
+pub struct Blueprint {
     ore: Cost,
     clay: Cost,
     obsidian: Cost,
@@ -233,6 +235,7 @@ fn calculate_geode_production(blueprint: &Blueprint, cycles: u16) -> u16 {
     targets
         .into_iter()
         .map(|target| {
+            check_cancelled();
             run_cycles(
                 State {
                     resources: StateCounters::default(),
@@ -249,8 +252,8 @@ fn calculate_geode_production(blueprint: &Blueprint, cycles: u16) -> u16 {
         .unwrap()
 }
 
-pub fn part1(input: &str) -> u16 {
-    let blueprints = parse_input(input);
+#[aoc::solution(day = 19, part = 1, parse = "parse_input")]
+pub fn part1(blueprints: &[Blueprint]) -> u16 {
     let mut result = 0;
     for (i, blueprint) in blueprints.iter().enumerate() {
         result += (i + 1) as u16 * calculate_geode_production(blueprint, 24);
@@ -258,8 +261,8 @@ pub fn part1(input: &str) -> u16 {
     result
 }
 
-pub fn part2(input: &str) -> u16 {
-    let blueprints = parse_input(input);
+#[aoc::solution(day = 19, part = 2, parse = "parse_input")]
+pub fn part2(blueprints: &[Blueprint]) -> u16 {
     return blueprints
         .iter()
         .take(3)
@@ -268,7 +271,7 @@ pub fn part2(input: &str) -> u16 {
 }
 
 fn main() {
-    run(part1, part2);
+    run_registered();
 }
 
 #[cfg(test)]
@@ -444,11 +447,11 @@ mod tests {
 
     #[test]
     fn example_part1() {
-        assert_eq!(part1(EXAMPLE_INPUT), 33);
+        assert_eq!(part1(&parse_input(EXAMPLE_INPUT)), 33);
     }
 
     #[test]
     fn example_part2() {
-        assert_eq!(part2(EXAMPLE_INPUT), 56 * 62);
+        assert_eq!(part2(&parse_input(EXAMPLE_INPUT)), 56 * 62);
     }
 }
@@ -1,6 +1,8 @@
+//! Grove Positioning System
+
 use std::collections::VecDeque;
 
-use aoc::runner::run;
+use aoc::runner::{check_cancelled, run_registered};
 
 fn parse_input(input: &str) -> Vec<i64> {
     return input
@@ -17,6 +19,7 @@ fn mix(numbers: Vec<i64>, times: usize) -> Vec<i64> {
     let len = mixing.len();
     for _ in 0..times {
         for i in 0..len {
+            check_cancelled();
             let (idx, offset) = mixing
                 .iter()
                 .enumerate()
@@ -38,6 +41,32 @@ fn mix(numbers: Vec<i64>, times: usize) -> Vec<i64> {
     mixing.into_iter().map(|(_i, n)| n).collect()
 }
 
+/// Like [`mix`], but rather than moving the numbers around this keeps track of the position of each number, shifting the positions of the numbers in between whenever one moves.
+fn mix_indexed(numbers: Vec<i64>, times: usize) -> Vec<i64> {
+    let len = numbers.len();
+    let mut positions: Vec<usize> = (0..len).collect();
+    for _ in 0..times {
+        for i in 0..len {
+            check_cancelled();
+            let from = positions[i];
+            let to = (from as i64 + numbers[i]).rem_euclid(len as i64 - 1) as usize;
+            for position in &mut positions {
+                if from < to && (from + 1..=to).contains(position) {
+                    *position -= 1;
+                } else if to < from && (to..from).contains(position) {
+                    *position += 1;
+                }
+            }
+            positions[i] = to;
+        }
+    }
+    let mut mixed = vec![0; len];
+    for (number, position) in numbers.into_iter().zip(positions) {
+        mixed[position] = number;
+    }
+    mixed
+}
+
 fn get_coordinates(numbers: &[i64]) -> i64 {
     let offset = numbers
         .iter()
@@ -49,12 +78,14 @@ fn get_coordinates(numbers: &[i64]) -> i64 {
     numbers[(1000 + offset) % len] + numbers[(2000 + offset) % len] + numbers[(3000 + offset) % len]
 }
 
+#[aoc::solution(day = 20, part = 1)]
 pub fn part1(input: &str) -> i64 {
     let numbers = parse_input(input);
     let numbers = mix(numbers, 1);
     get_coordinates(&numbers)
 }
 
+#[aoc::solution(day = 20, part = 2)]
 pub fn part2(input: &str) -> i64 {
     let numbers = parse_input(input);
     let numbers = numbers.into_iter().map(|n| n * 811_589_153).collect();
@@ -62,8 +93,23 @@ pub fn part2(input: &str) -> i64 {
     get_coordinates(&numbers)
 }
 
+#[aoc::solution(day = 20, part = 1, alternative = "indexed")]
+pub fn part1_indexed(input: &str) -> i64 {
+    let numbers = parse_input(input);
+    let numbers = mix_indexed(numbers, 1);
+    get_coordinates(&numbers)
+}
+
+#[aoc::solution(day = 20, part = 2, alternative = "indexed")]
+pub fn part2_indexed(input: &str) -> i64 {
+    let numbers = parse_input(input);
+    let numbers = numbers.into_iter().map(|n| n * 811_589_153).collect();
+    let numbers = mix_indexed(numbers, 10);
+    get_coordinates(&numbers)
+}
+
 fn main() {
-    run(part1, part2);
+    run_registered();
 }
 
 #[cfg(test)]
@@ -115,6 +161,17 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn example_mix_indexed() {
+        let start = vec![1, 2, -3, 3, -2, 0, 4];
+        for times in 1..=10 {
+            assert_eq!(
+                zerofirst(mix_indexed(start.clone(), times)),
+                zerofirst(mix(start.clone(), times))
+            );
+        }
+    }
+
     #[test]
     #[allow(clippy::too_many_lines)]
     fn example_mix_multi() {
@@ -258,4 +315,14 @@ mod tests {
     fn example_part2() {
         assert_eq!(part2(EXAMPLE_INPUT), 1_623_178_306);
     }
+
+    #[test]
+    fn example_part1_indexed() {
+        assert_eq!(part1_indexed(EXAMPLE_INPUT), 3);
+    }
+
+    #[test]
+    fn example_part2_indexed() {
+        assert_eq!(part2_indexed(EXAMPLE_INPUT), 1_623_178_306);
+    }
 }
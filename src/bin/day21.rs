@@ -1,6 +1,8 @@
+//! Monkey Math
+
 use std::collections::HashMap;
 
-use aoc::runner::run;
+use aoc::runner::run_registered;
 
 #[derive(Debug, Eq, PartialEq)]
 enum Operation {
@@ -16,7 +18,7 @@ enum Job<'a> {
     Operation(&'a str, Operation, &'a str),
 }
 
-fn parse_input(input: &str) -> HashMap<&str, Job> {
+fn parse_input(input: &str) -> HashMap<&str, Job<'_>> {
     return input
         .trim()
         .split('\n')
@@ -45,6 +47,7 @@ fn parse_input(input: &str) -> HashMap<&str, Job> {
         .collect();
 }
 
+#[aoc::solution(day = 21, part = 1)]
 pub fn part1(input: &str) -> u64 {
     let mut jobs = parse_input(input);
     let mut results: HashMap<&str, u64> = HashMap::new();
@@ -83,6 +86,7 @@ pub fn part1(input: &str) -> u64 {
     return *results.get("root").unwrap();
 }
 
+#[aoc::solution(day = 21, part = 2)]
 pub fn part2(input: &str) -> u64 {
     let mut jobs = parse_input(input);
     let mut results: HashMap<&str, u64> = HashMap::new();
@@ -179,7 +183,7 @@ pub fn part2(input: &str) -> u64 {
 }
 
 fn main() {
-    run(part1, part2);
+    run_registered();
 }
 
 #[cfg(test)]
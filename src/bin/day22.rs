@@ -1,9 +1,11 @@
+//! Monkey Map
+
 use std::{
     collections::HashSet,
     ops::{Add, Sub},
 };
 
-use aoc::{grid::Point, runner::run};
+use aoc::{grid::Point, runner::run_registered};
 
 type BlockPoint = Point<isize>;
 
@@ -432,12 +434,14 @@ fn map_faces_cube(grid: &Grid) -> Directions {
         .unwrap();
 }
 
+#[aoc::solution(day = 22, part = 1)]
 pub fn part1(input: &str) -> usize {
     let (grid, actions) = parse_input(input);
     let directions = map_faces_grid(&grid);
     process(grid, actions, directions)
 }
 
+#[aoc::solution(day = 22, part = 2)]
 pub fn part2(input: &str) -> usize {
     let (grid, actions) = parse_input(input);
     let directions = map_faces_cube(&grid);
@@ -445,7 +449,7 @@ pub fn part2(input: &str) -> usize {
 }
 
 fn main() {
-    run(part1, part2);
+    run_registered();
 }
 
 #[cfg(test)]
@@ -1,6 +1,11 @@
+//! Unstable Diffusion
+
 use std::collections::HashSet;
 
-use aoc::{grid::Point as BasePoint, runner::run};
+use aoc::{
+    grid::Point as BasePoint,
+    runner::{check_cancelled, run_registered},
+};
 
 type Point = BasePoint<isize>;
 
@@ -131,6 +136,7 @@ fn cycle(state: &mut State) {
     state.directions.push(direction);
 }
 
+#[aoc::solution(day = 23, part = 1)]
 pub fn part1(input: &str) -> usize {
     let elves = parse_input(input);
     let mut state = State {
@@ -149,6 +155,7 @@ pub fn part1(input: &str) -> usize {
     ((x_max - x_min + 1) * (y_max - y_min + 1)) as usize - state.elves.len()
 }
 
+#[aoc::solution(day = 23, part = 2)]
 pub fn part2(input: &str) -> usize {
     let elves = parse_input(input);
     let mut prev = elves.clone();
@@ -158,6 +165,7 @@ pub fn part2(input: &str) -> usize {
     };
     let mut round = 0;
     loop {
+        check_cancelled();
         round += 1;
         cycle(&mut state);
         if state.elves == prev {
@@ -169,7 +177,7 @@ pub fn part2(input: &str) -> usize {
 }
 
 fn main() {
-    run(part1, part2);
+    run_registered();
 }
 
 #[cfg(test)]
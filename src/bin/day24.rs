@@ -1,6 +1,11 @@
+//! Blizzard Basin
+
 use std::{collections::HashSet, ops::Range};
 
-use aoc::{grid::Point as BasePoint, runner::run};
+use aoc::{
+    grid::Point as BasePoint,
+    runner::{check_cancelled, run_registered},
+};
 
 type Point = BasePoint<u8>;
 
@@ -117,6 +122,7 @@ fn navigate(map: &mut Map, start: Point, end: Point) -> usize {
     points.insert(start);
     let mut i = 0;
     loop {
+        check_cancelled();
         i += 1;
         let generation = map.next_generation();
 
@@ -162,6 +168,7 @@ fn navigate(map: &mut Map, start: Point, end: Point) -> usize {
     }
 }
 
+#[aoc::solution(day = 24, part = 1)]
 pub fn part1(input: &str) -> usize {
     let mut map = parse_input(input);
     let start = map.start;
@@ -169,6 +176,7 @@ pub fn part1(input: &str) -> usize {
     navigate(&mut map, start, end)
 }
 
+#[aoc::solution(day = 24, part = 2)]
 pub fn part2(input: &str) -> usize {
     let mut map = parse_input(input);
     let start = map.start;
@@ -177,7 +185,7 @@ pub fn part2(input: &str) -> usize {
 }
 
 fn main() {
-    run(part1, part2);
+    run_registered();
 }
 
 #[cfg(test)]
@@ -1,4 +1,6 @@
-use aoc::runner::run;
+//! Full of Hot Air
+
+use aoc::runner::run_registered;
 
 fn to_snafu(mut num: u64) -> String {
     let mut chars = Vec::new();
@@ -33,18 +35,20 @@ fn from_snafu(num: &str) -> u64 {
     result as u64
 }
 
+#[aoc::solution(day = 25, part = 1)]
 pub fn part1(input: &str) -> String {
     let lines = input.trim().split('\n').map(str::trim);
     let numbers = lines.map(from_snafu);
     to_snafu(numbers.sum())
 }
 
+#[aoc::solution(day = 25, part = 2)]
 pub fn part2(_input: &str) -> &'static str {
     "I did it!"
 }
 
 fn main() {
-    run(part1, part2);
+    run_registered();
 }
 
 #[cfg(test)]
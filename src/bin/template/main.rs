@@ -1,18 +1,19 @@
 #![allow(unused_variables)]
 
-use aoc::runner::run;
+use aoc::runner::run_registered;
 
 fn parse_input(input: &str) -> usize {
     0
 }
 
+// Register the parts using `#[aoc::solution(day = N, part = 1)]` after copying this file to `src/bin/dayNN.rs`, which is how both this binary and the aggregate runner find them.
 pub fn part1(input: &str) -> usize {
     let input = parse_input(input);
     0
 }
 
 fn main() {
-    run(part1, ());
+    run_registered();
 }
 
 #[cfg(test)]
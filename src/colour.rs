@@ -0,0 +1,80 @@
+#![allow(non_upper_case_globals)]
+
+use std::{
+    borrow::Cow,
+    env,
+    fmt::Debug,
+    io::{self, IsTerminal},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use ansi_term::{ANSIGenericString, Style};
+
+static ENABLED: AtomicBool = AtomicBool::new(true);
+static ENABLED_STDERR: AtomicBool = AtomicBool::new(true);
+
+/// Enable or disable colours for all output.
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+    ENABLED_STDERR.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether colours are enabled for output to stdout.
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Whether colours are enabled for output to stderr.
+pub fn is_enabled_stderr() -> bool {
+    ENABLED_STDERR.load(Ordering::Relaxed)
+}
+
+/// Disable colours if the `NO_COLOR` environment variable is set (see <https://no-color.org>), and for stdout and stderr separately if they are not a terminal.
+pub fn init() {
+    let no_color = env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+    ENABLED.store(!no_color && io::stdout().is_terminal(), Ordering::Relaxed);
+    ENABLED_STDERR.store(!no_color && io::stderr().is_terminal(), Ordering::Relaxed);
+}
+
+fn gate(style: Style, enabled: bool) -> Style {
+    if enabled {
+        style
+    } else {
+        Style::default()
+    }
+}
+
+/// A wrapper around [`ansi_term::Colour`] that leaves the text unstyled when colours are disabled.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Colour(ansi_term::Colour);
+impl Colour {
+    pub fn paint<'a, I, S>(self, input: I) -> ANSIGenericString<'a, S>
+    where
+        I: Into<Cow<'a, S>>,
+        S: 'a + ToOwned + ?Sized,
+        <S as ToOwned>::Owned: Debug,
+    {
+        gate(self.0.normal(), is_enabled()).paint(input)
+    }
+
+    /// Like [`Colour::paint`], but for text that is written to stderr.
+    pub fn paint_err<'a, I, S>(self, input: I) -> ANSIGenericString<'a, S>
+    where
+        I: Into<Cow<'a, S>>,
+        S: 'a + ToOwned + ?Sized,
+        <S as ToOwned>::Owned: Debug,
+    {
+        gate(self.0.normal(), is_enabled_stderr()).paint(input)
+    }
+
+    /// Get a style with this colour as its background instead of its foreground.
+    pub fn reverse(self) -> Style {
+        gate(self.0.reverse(), is_enabled())
+    }
+}
+
+pub const Blue: Colour = Colour(ansi_term::Colour::Blue);
+pub const Cyan: Colour = Colour(ansi_term::Colour::Cyan);
+pub const Green: Colour = Colour(ansi_term::Colour::Green);
+pub const Purple: Colour = Colour(ansi_term::Colour::Purple);
+pub const Red: Colour = Colour(ansi_term::Colour::Red);
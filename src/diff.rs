@@ -0,0 +1,140 @@
+use crate::{
+    colour::{Colour, Green, Red},
+    ocr::recognise_text,
+};
+
+/// Normalise a result for comparison by removing trailing whitespace from every line as well as any trailing newlines.
+pub fn normalise(value: &str) -> String {
+    value
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim_end_matches('\n')
+        .to_string()
+}
+
+/// Check whether a result matches the expected solution, ignoring differences in trailing whitespace.
+///
+/// A pixel-art result also matches if the letters it spells (see [`crate::ocr`]) are the expected solution, and vice versa.
+pub fn matches(result: &str, expected: &str) -> bool {
+    let (result, expected) = (normalise(result), normalise(expected));
+    if result == expected {
+        return true;
+    }
+    if result.contains('\n') == expected.contains('\n') {
+        return false;
+    }
+    let recognised = if result.contains('\n') {
+        recognise_text(&result).map(|letters| letters == expected)
+    } else {
+        recognise_text(&expected).map(|letters| letters == result)
+    };
+    recognised.unwrap_or(false)
+}
+
+/// Find the row & column (both starting at 1) of the first character that differs between the result and the expected solution.
+pub fn first_mismatch(result: &str, expected: &str) -> Option<(usize, usize)> {
+    let (result, expected) = (normalise(result), normalise(expected));
+    let result_lines: Vec<&str> = result.split('\n').collect();
+    let expected_lines: Vec<&str> = expected.split('\n').collect();
+    for row in 0..result_lines.len().max(expected_lines.len()) {
+        let (result_line, expected_line) = (result_lines.get(row), expected_lines.get(row));
+        if result_line != expected_line {
+            let column = result_line
+                .unwrap_or(&"")
+                .chars()
+                .zip(expected_line.unwrap_or(&"").chars())
+                .take_while(|(left, right)| left == right)
+                .count();
+            return Some((row + 1, column + 1));
+        }
+    }
+    None
+}
+
+/// Highlight the characters of the line that differ from the other line in the given colour.
+///
+/// The colours are reversed so that cells containing whitespace are highlighted as well, which matters for pixel-art results.
+fn highlight(line: &str, other: &str, colour: Colour) -> String {
+    let mut other = other.chars();
+    line.chars()
+        .map(|chr| {
+            if other.next() == Some(chr) {
+                chr.to_string()
+            } else {
+                colour.reverse().paint(chr.to_string()).to_string()
+            }
+        })
+        .collect()
+}
+
+/// Render a line-by-line comparison of a multi-line result with the expected solution.
+///
+/// Lines that match are shown once. For lines that differ the expected line is shown prefixed with `-` and the actual line prefixed with `+`, with the differing characters highlighted.
+pub fn render(result: &str, expected: &str) -> Vec<String> {
+    let (result, expected) = (normalise(result), normalise(expected));
+    let result_lines: Vec<&str> = result.split('\n').collect();
+    let expected_lines: Vec<&str> = expected.split('\n').collect();
+    let mut rendered = Vec::new();
+    for row in 0..result_lines.len().max(expected_lines.len()) {
+        match (result_lines.get(row), expected_lines.get(row)) {
+            (Some(result_line), Some(expected_line)) if result_line == expected_line => {
+                rendered.push(format!("  {result_line}"));
+            }
+            (result_line, expected_line) => {
+                let result_line = result_line.copied().unwrap_or_default();
+                let expected_line = expected_line.copied().unwrap_or_default();
+                rendered.push(format!(
+                    "- {}",
+                    highlight(expected_line, result_line, Green)
+                ));
+                rendered.push(format!("+ {}", highlight(result_line, expected_line, Red)));
+            }
+        }
+    }
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn normalise_trailing_whitespace() {
+        assert_eq!(normalise("##  \n.#\t\n\n"), "##\n.#");
+        assert!(matches("##  \n.#\n", "##\n.#"));
+        assert!(!matches("##\n.#", "##\n#."));
+    }
+
+    #[test]
+    fn matches_letters() {
+        let pixels = "#..#.\n#..#.\n####.\n#..#.\n#..#.\n#..#.\n";
+        assert!(matches(pixels, "H"));
+        assert!(matches("H", &pixels.replace('#', "█").replace('.', " ")));
+        assert!(!matches(pixels, "A"));
+    }
+
+    #[test]
+    fn mismatch_position() {
+        assert_eq!(first_mismatch("##\n.#\n", "##  \n.#"), None);
+        assert_eq!(first_mismatch("##\n.#", "##\n..#"), Some((2, 2)));
+        assert_eq!(first_mismatch("##\n.#", "##\n.#."), Some((2, 3)));
+        assert_eq!(first_mismatch("##", "##\n.#"), Some((2, 1)));
+    }
+
+    #[test]
+    fn render_diff() {
+        assert_eq!(
+            render("##\n.#\n#.", "##\n..\n#."),
+            vec![
+                "  ##".to_string(),
+                format!("- .{}", Green.reverse().paint(".")),
+                format!("+ .{}", Red.reverse().paint("#")),
+                "  #.".to_string(),
+            ]
+        );
+    }
+}
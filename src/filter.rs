@@ -0,0 +1,107 @@
+/// A selection of days, as given on the command line.
+#[derive(Debug, PartialEq)]
+pub enum DayFilter {
+    /// A single day by number, e.g. `3`.
+    Day(u8),
+    /// An inclusive range of days by number, e.g. `5-9`.
+    Range(u8, u8),
+    /// A glob pattern matched against the name of the day, e.g. `day1*`.
+    Glob(String),
+}
+impl DayFilter {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        if value.contains(['*', '?']) {
+            return Ok(DayFilter::Glob(value.to_string()));
+        }
+        if let Some((start, end)) = value.split_once('-') {
+            let start = parse_day_number(start)?;
+            let end = parse_day_number(end)?;
+            if start > end {
+                return Err(format!("Invalid day range '{value}'."));
+            }
+            return Ok(DayFilter::Range(start, end));
+        }
+        Ok(DayFilter::Day(parse_day_number(value)?))
+    }
+
+    /// Check whether the day with the given name (e.g. `day03`) is selected by this filter.
+    pub fn matches(&self, name: &str) -> bool {
+        match self {
+            DayFilter::Day(day) => get_day_number(name) == Some(*day),
+            DayFilter::Range(start, end) => {
+                get_day_number(name).is_some_and(|day| *start <= day && day <= *end)
+            }
+            DayFilter::Glob(pattern) => glob_matches(pattern, name),
+        }
+    }
+}
+
+fn parse_day_number(value: &str) -> Result<u8, String> {
+    get_day_number(value).ok_or(format!("Invalid day '{value}'."))
+}
+
+fn get_day_number(name: &str) -> Option<u8> {
+    name.trim_start_matches("day").parse().ok()
+}
+
+fn glob_matches(pattern: &str, value: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let value: Vec<char> = value.chars().collect();
+    // matches[j] is whether the pattern so far matches the first j characters of the value.
+    let mut matches = vec![false; value.len() + 1];
+    matches[0] = true;
+    for chr in pattern {
+        let mut next = vec![false; value.len() + 1];
+        for j in 0..=value.len() {
+            next[j] = match chr {
+                '*' => matches[j] || (j > 0 && next[j - 1]),
+                '?' => j > 0 && matches[j - 1],
+                chr => j > 0 && matches[j - 1] && value[j - 1] == chr,
+            };
+        }
+        matches = next;
+    }
+    matches[value.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!(DayFilter::parse("3"), Ok(DayFilter::Day(3)));
+        assert_eq!(DayFilter::parse("day03"), Ok(DayFilter::Day(3)));
+        assert_eq!(DayFilter::parse("5-9"), Ok(DayFilter::Range(5, 9)));
+        assert_eq!(
+            DayFilter::parse("day1*"),
+            Ok(DayFilter::Glob("day1*".to_string()))
+        );
+        assert_eq!(
+            DayFilter::parse("foo"),
+            Err("Invalid day 'foo'.".to_string())
+        );
+        assert_eq!(
+            DayFilter::parse("9-5"),
+            Err("Invalid day range '9-5'.".to_string())
+        );
+    }
+
+    #[test]
+    fn matches() {
+        assert!(DayFilter::Day(3).matches("day03"));
+        assert!(!DayFilter::Day(3).matches("day13"));
+        assert!(DayFilter::Range(5, 9).matches("day05"));
+        assert!(DayFilter::Range(5, 9).matches("day09"));
+        assert!(!DayFilter::Range(5, 9).matches("day10"));
+        let glob = DayFilter::Glob("day1*".to_string());
+        assert!(glob.matches("day10"));
+        assert!(glob.matches("day19"));
+        assert!(!glob.matches("day01"));
+        assert!(!glob.matches("day20"));
+        assert!(DayFilter::Glob("day?5".to_string()).matches("day25"));
+        assert!(!DayFilter::Glob("day?5".to_string()).matches("day5"));
+    }
+}
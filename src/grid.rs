@@ -115,7 +115,7 @@ impl<T: Debug> Grid<T> {
         self.mutate(point.x, point.y, mutator);
     }
 
-    pub fn iter(&self) -> Iter<Vec<T>> {
+    pub fn iter(&self) -> Iter<'_, Vec<T>> {
         self.items.iter()
     }
 
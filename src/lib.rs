@@ -1,7 +1,22 @@
+pub mod answers;
+pub mod baseline;
+pub mod colour;
 pub mod counter;
+pub mod diff;
+pub mod filter;
 pub mod grid;
+pub mod memory;
+pub mod ocr;
+pub mod pool;
 pub mod range;
+pub mod report;
 pub mod runner;
+pub mod solution;
+pub mod watch;
+
+pub use aoc_derive::solution;
+#[doc(hidden)]
+pub use inventory;
 
 pub fn parse_number_list(input: &str, sep: &str) -> Vec<i32> {
     return input
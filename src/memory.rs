@@ -0,0 +1,200 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt::Display,
+};
+
+/// Memory usage of a single run.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct AllocationStats {
+    /// The number of allocations (including reallocations) that were made.
+    pub count: usize,
+    /// The total number of bytes that were allocated, including those that were freed again.
+    pub bytes: usize,
+    /// The highest number of bytes that were allocated at the same time.
+    pub peak: usize,
+}
+impl Display for AllocationStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocs, {} allocated, {} peak",
+            self.count,
+            format_bytes(self.bytes),
+            format_bytes(self.peak),
+        )
+    }
+}
+
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut unit = 0;
+    while bytes >= 1024usize.pow(unit + 1) && (unit as usize) < UNITS.len() - 1 {
+        unit += 1;
+    }
+    if unit == 0 {
+        return format!("{bytes}{}", UNITS[0]);
+    }
+    let tenths = bytes * 10 / 1024usize.pow(unit);
+    format!("{}.{}{}", tenths / 10, tenths % 10, UNITS[unit as usize])
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct Tracker {
+    enabled: bool,
+    stats: AllocationStats,
+    /// The number of bytes currently allocated. Memory that was allocated before tracking started can be freed while tracking, so this can go negative.
+    live: isize,
+}
+impl Tracker {
+    fn alloc(&mut self, size: usize) {
+        self.stats.count += 1;
+        self.stats.bytes += size;
+        self.resize(size as isize);
+    }
+
+    fn dealloc(&mut self, size: usize) {
+        self.resize(-(size as isize));
+    }
+
+    fn realloc(&mut self, old_size: usize, new_size: usize) {
+        self.stats.count += 1;
+        self.stats.bytes += new_size;
+        self.resize(new_size as isize - old_size as isize);
+    }
+
+    fn resize(&mut self, change: isize) {
+        self.live += change;
+        self.stats.peak = self.stats.peak.max(self.live.max(0) as usize);
+    }
+}
+
+thread_local! {
+    static TRACKER: Cell<Tracker> = const {
+        Cell::new(Tracker {
+            enabled: false,
+            stats: AllocationStats { count: 0, bytes: 0, peak: 0 },
+            live: 0,
+        })
+    };
+}
+
+fn update(f: impl FnOnce(&mut Tracker)) {
+    // This may be called while the thread is being torn down, in which case there is nothing to track anymore.
+    let _ = TRACKER.try_with(|cell| {
+        let mut tracker = cell.get();
+        if tracker.enabled {
+            f(&mut tracker);
+            cell.set(tracker);
+        }
+    });
+}
+
+/// A global allocator that keeps track of the allocations made on each thread while [`track`] is active on it.
+///
+/// This is installed when the `track-allocations` feature is enabled.
+pub struct CountingAllocator;
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            update(|tracker| tracker.alloc(layout.size()));
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            update(|tracker| tracker.alloc(layout.size()));
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        update(|tracker| tracker.dealloc(layout.size()));
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            update(|tracker| tracker.realloc(layout.size(), new_size));
+        }
+        new_ptr
+    }
+}
+
+#[cfg(feature = "track-allocations")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Whether allocations are being counted, i.e. whether the `track-allocations` feature is enabled.
+pub fn is_enabled() -> bool {
+    cfg!(feature = "track-allocations")
+}
+
+/// Run the given function, counting the allocations it makes on the current thread.
+///
+/// Returns `None` for the stats if allocation tracking is not enabled.
+pub fn track<R>(f: impl FnOnce() -> R) -> (R, Option<AllocationStats>) {
+    if !is_enabled() {
+        return (f(), None);
+    }
+
+    TRACKER.set(Tracker {
+        enabled: true,
+        ..Tracker::default()
+    });
+    let result = f();
+    let tracker = TRACKER.replace(Tracker::default());
+    (result, Some(tracker.stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn tracker() {
+        let mut tracker = Tracker::default();
+        tracker.alloc(100);
+        tracker.alloc(50);
+        tracker.dealloc(100);
+        tracker.realloc(50, 200);
+        tracker.dealloc(200);
+        // Freeing memory that was allocated before tracking started should not affect the peak.
+        tracker.dealloc(1000);
+        tracker.alloc(10);
+        assert_eq!(
+            tracker.stats,
+            AllocationStats {
+                count: 4,
+                bytes: 360,
+                peak: 200,
+            }
+        );
+    }
+
+    #[cfg(feature = "track-allocations")]
+    #[test]
+    fn track_vec() {
+        let (_, stats) = track(|| vec![0u8; 4096].len());
+        let stats = stats.unwrap();
+        assert_eq!(stats.count, 1);
+        assert_eq!(stats.bytes, 4096);
+        assert_eq!(stats.peak, 4096);
+    }
+
+    #[test]
+    fn display() {
+        let stats = AllocationStats {
+            count: 12,
+            bytes: 3 * 1024 * 1024 + 512 * 1024,
+            peak: 800,
+        };
+        assert_eq!(stats.to_string(), "12 allocs, 3.5MiB allocated, 800B peak");
+    }
+}
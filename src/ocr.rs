@@ -0,0 +1,258 @@
+use crate::grid::Grid;
+
+/// A font used for the letters in pixel-art answers, with the glyphs given as rows of `#` (on) and `.` (off).
+///
+/// Most glyphs leave a column empty before the next letter, but some (such as `Y`) are wider and use it as well, so the width of each glyph follows from the length of its pattern.
+struct Font {
+    height: usize,
+    /// The distance between the start of two consecutive letters.
+    stride: usize,
+    glyphs: &'static [(char, &'static str)],
+}
+
+const FONT_SMALL: Font = Font {
+    height: 6,
+    stride: 5,
+    glyphs: &[
+        ('A', ".##.#..##..######..##..#"),
+        ('B', "###.#..####.#..##..####."),
+        ('C', ".##.#..##...#...#..#.##."),
+        ('E', "#####...###.#...#...####"),
+        ('F', "#####...###.#...#...#..."),
+        ('G', ".##.#..##...#.###..#.###"),
+        ('H', "#..##..######..##..##..#"),
+        ('I', ".###..#...#...#...#..###"),
+        ('J', "..##...#...#...##..#.##."),
+        ('K', "#..##.#.##..#.#.#.#.#..#"),
+        ('L', "#...#...#...#...#...####"),
+        ('O', ".##.#..##..##..##..#.##."),
+        ('P', "###.#..##..####.#...#..."),
+        ('R', "###.#..##..####.#.#.#..#"),
+        ('S', ".####...#....##....####."),
+        ('U', "#..##..##..##..##..#.##."),
+        ('Y', "#...##...#.#.#...#....#....#.."),
+        ('Z', "####...#..#..#..#...####"),
+    ],
+};
+
+const FONT_LARGE: Font = Font {
+    height: 10,
+    stride: 8,
+    glyphs: &[
+        (
+            'A',
+            "..##...#..#.#....##....##....########....##....##....##....#",
+        ),
+        (
+            'B',
+            "#####.#....##....##....######.#....##....##....##....######.",
+        ),
+        (
+            'C',
+            ".####.#....##.....#.....#.....#.....#.....#.....#....#.####.",
+        ),
+        (
+            'E',
+            "#######.....#.....#.....#####.#.....#.....#.....#.....######",
+        ),
+        (
+            'F',
+            "#######.....#.....#.....#####.#.....#.....#.....#.....#.....",
+        ),
+        (
+            'G',
+            ".####.#....##.....#.....#.....#..####....##....##...##.###.#",
+        ),
+        (
+            'H',
+            "#....##....##....##....########....##....##....##....##....#",
+        ),
+        (
+            'J',
+            "...###....#.....#.....#.....#.....#.....#.#...#.#...#..###..",
+        ),
+        (
+            'K',
+            "#....##...#.#..#..#.#...##....##....#.#...#..#..#...#.#....#",
+        ),
+        (
+            'L',
+            "#.....#.....#.....#.....#.....#.....#.....#.....#.....######",
+        ),
+        (
+            'N',
+            "#....###...###...##.#..##.#..##..#.##..#.##...###...###....#",
+        ),
+        (
+            'P',
+            "#####.#....##....##....######.#.....#.....#.....#.....#.....",
+        ),
+        (
+            'R',
+            "#####.#....##....##....######.#..#..#...#.#...#.#....##....#",
+        ),
+        (
+            'X',
+            "#....##....#.#..#..#..#...##....##...#..#..#..#.#....##....#",
+        ),
+        (
+            'Z',
+            "######.....#.....#....#....#....#....#....#.....#.....######",
+        ),
+    ],
+};
+
+/// Recognise the letters in a pixel-art answer, using the standard font used by the puzzles.
+///
+/// Empty rows & columns around the letters are ignored. Returns `None` if the pixels do not form (only) known letters.
+pub fn recognise(pixels: &[Vec<bool>]) -> Option<String> {
+    let rows: Vec<&Vec<bool>> = pixels.iter().filter(|row| row.contains(&true)).collect();
+    let font = [FONT_SMALL, FONT_LARGE]
+        .into_iter()
+        .find(|font| font.height == rows.len())?;
+    let first = (0..rows.iter().map(|row| row.len()).max()?)
+        .find(|x| rows.iter().any(|row| row.get(*x) == Some(&true)))?;
+    let last = rows
+        .iter()
+        .filter_map(|row| row.iter().rposition(|pixel| *pixel))
+        .max()?;
+
+    // Some glyphs (such as `I`) start with an empty column, in which case the first letter starts before the first pixel.
+    (0..font.stride).find_map(|shift| recognise_from(&rows, &font, first, shift, last))
+}
+
+/// Recognise the letters in the given rows, with the first letter starting `shift` columns before `first`.
+fn recognise_from(
+    rows: &[&Vec<bool>],
+    font: &Font,
+    first: usize,
+    shift: usize,
+    last: usize,
+) -> Option<String> {
+    // Columns before the start of the rows are empty, which happens if the pixels are cropped to the first pixel.
+    let pixel = |row: &Vec<bool>, x: usize| {
+        x.checked_sub(shift)
+            .and_then(|x| row.get(x))
+            .is_some_and(|pixel| *pixel)
+    };
+    let count = (last + shift + 1 - first).div_ceil(font.stride);
+    let mut result = String::with_capacity(count);
+    for i in 0..count {
+        let start = first + i * font.stride;
+        let (letter, _) = font.glyphs.iter().find(|(_, pattern)| {
+            let width = pattern.len() / font.height;
+            let glyph: String = rows
+                .iter()
+                .flat_map(|row| {
+                    (start..start + width).map(|x| if pixel(row, x) { '#' } else { '.' })
+                })
+                .collect();
+            *pattern == glyph
+        })?;
+        result.push(*letter);
+    }
+    Some(result)
+}
+
+/// Recognise the letters in a grid where `true` means a pixel is on. See [`recognise`].
+pub fn recognise_grid(grid: &Grid<bool>) -> Option<String> {
+    let pixels: Vec<Vec<bool>> = grid.iter().cloned().collect();
+    recognise(&pixels)
+}
+
+/// Recognise the letters in a block of text where `#` or `█` means a pixel is on. See [`recognise`].
+pub fn recognise_text(text: &str) -> Option<String> {
+    let pixels: Vec<Vec<bool>> = text
+        .lines()
+        .map(|line| line.chars().map(|chr| chr == '#' || chr == '█').collect())
+        .collect();
+    recognise(&pixels)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn small() {
+        let text = [
+            "",
+            "  #  #  ##  ###  ####   ",
+            "  #  # #  # #  #    #   ",
+            "  #### #  # #  #   #    ",
+            "  #  # #### ###   #     ",
+            "  #  # #  # #    #      ",
+            "  #  # #  # #    ####   ",
+            "",
+        ]
+        .join("\n");
+        assert_eq!(recognise_text(&text), Some("HAPZ".to_string()));
+        assert_eq!(
+            recognise_text(&text.replace('#', "█")),
+            Some("HAPZ".to_string())
+        );
+    }
+
+    #[test]
+    fn small_leading_empty_column() {
+        let text = [
+            " ### #   #### ",
+            "  #  #   ##  #",
+            "  #   # # #  #",
+            "  #    #  ### ",
+            "  #    #  #   ",
+            " ###   #  #   ",
+        ]
+        .join("\n");
+        assert_eq!(recognise_text(&text), Some("IYP".to_string()));
+        let cropped: Vec<&str> = text.lines().map(|line| &line[1..]).collect();
+        assert_eq!(recognise_text(&cropped.join("\n")), Some("IYP".to_string()));
+    }
+
+    #[test]
+    fn large() {
+        let text = [
+            "#....#..#####.",
+            "#....#..#....#",
+            ".#..#...#....#",
+            ".#..#...#....#",
+            "..##....#####.",
+            "..##....#.....",
+            ".#..#...#.....",
+            ".#..#...#.....",
+            "#....#..#.....",
+            "#....#..#.....",
+        ]
+        .join("\n");
+        assert_eq!(recognise_text(&text), Some("XP".to_string()));
+    }
+
+    #[test]
+    fn grid() {
+        let grid: Grid<bool> = FONT_SMALL.glyphs[0]
+            .1
+            .as_bytes()
+            .chunks(4)
+            .map(|row| row.iter().map(|b| *b == b'#').collect::<Vec<_>>())
+            .collect();
+        assert_eq!(recognise_grid(&grid), Some("A".to_string()));
+    }
+
+    #[test]
+    fn unknown() {
+        assert_eq!(recognise_text("#.#\n.#.\n#.#"), None);
+        assert_eq!(recognise_text(""), None);
+    }
+
+    #[test]
+    fn glyph_sizes() {
+        for font in [FONT_SMALL, FONT_LARGE] {
+            for (letter, pattern) in font.glyphs {
+                assert_eq!(pattern.len() % font.height, 0, "{letter}");
+                assert!(pattern.len() / font.height <= font.stride, "{letter}");
+            }
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use std::{
+    sync::Mutex,
+    thread::{self, available_parallelism},
+};
+
+use crate::runner::WORKER_STACK_SIZE;
+
+/// Get the number of threads to use for the given number of jobs, where `0` means one per available CPU core.
+pub fn get_thread_count(jobs: usize) -> usize {
+    if jobs == 0 {
+        available_parallelism().map_or(1, usize::from)
+    } else {
+        jobs
+    }
+}
+
+/// Apply the given function to all items using the given number of threads, returning the results in the same order as the items.
+pub fn map<T, R, F>(items: Vec<T>, threads: usize, f: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync,
+{
+    let count = items.len();
+    let queue = Mutex::new(items.into_iter().enumerate());
+    let results = Mutex::new((0..count).map(|_| None).collect::<Vec<Option<R>>>());
+
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, count.max(1)) {
+            // Parts are run directly on these threads when there is no timeout, so they need as much stack as the worker threads.
+            thread::Builder::new()
+                .stack_size(WORKER_STACK_SIZE)
+                .spawn_scoped(scope, || loop {
+                    let Some((idx, item)) = queue.lock().unwrap().next() else {
+                        break;
+                    };
+                    let result = f(item);
+                    results.lock().unwrap()[idx] = Some(result);
+                })
+                .expect("Unable to spawn worker thread.");
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.expect("Worker did not produce a result."))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn map_keeps_order() {
+        let items: Vec<u64> = (0..20).collect();
+        let results = map(items, 4, |i| {
+            // Make the earlier items finish last.
+            thread::sleep(Duration::from_millis(20 - i));
+            i * 2
+        });
+        assert_eq!(results, (0..20).map(|i| i * 2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn map_empty() {
+        let results = map(Vec::<u8>::new(), 4, |i| i);
+        assert_eq!(results, Vec::<u8>::new());
+    }
+}
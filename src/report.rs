@@ -0,0 +1,725 @@
+use std::{borrow::Cow, fmt::Write, time::Duration};
+
+use crate::{
+    diff,
+    runner::{PartRun, RunnableRun, RunnableRunErr},
+    solution::Answer,
+};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Status {
+    /// The result matches the expected solution.
+    Pass,
+    /// The result does not match the expected solution.
+    Fail,
+    /// There is no expected solution to compare the result with.
+    Unverified,
+    /// The part has not been implemented.
+    Missing,
+    /// The part returned an error, e.g. because the input is invalid.
+    ReturnedError,
+    /// The part could not be run to completion.
+    Error,
+}
+impl Status {
+    pub fn of(run: &RunnableRun) -> Self {
+        match run {
+            Ok(run) => match &run.solution {
+                Some(solution) if diff::matches(&run.result.as_text(), solution) => Status::Pass,
+                Some(_) => Status::Fail,
+                None => Status::Unverified,
+            },
+            Err(RunnableRunErr::Missing) => Status::Missing,
+            Err(RunnableRunErr::ReturnedError(_)) => Status::ReturnedError,
+            Err(_) => Status::Error,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail => "fail",
+            Status::Unverified => "unverified",
+            Status::Missing => "missing",
+            Status::ReturnedError => "errored",
+            Status::Error => "error",
+        }
+    }
+}
+
+/// The totals over all parts that ran successfully.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Summary {
+    pub count: usize,
+    pub total: Duration,
+    pub average: Duration,
+}
+impl Summary {
+    pub fn of(durations: impl IntoIterator<Item = Duration>) -> Self {
+        let durations: Vec<Duration> = durations.into_iter().collect();
+        let total = durations.iter().sum::<Duration>();
+        let average = if durations.is_empty() {
+            Duration::from_secs(0)
+        } else {
+            total / durations.len() as u32
+        };
+        Self {
+            count: durations.len(),
+            total,
+            average,
+        }
+    }
+}
+
+/// A single part of a single day, in a form that is suitable for machine-readable reports.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+    pub day: String,
+    pub part: usize,
+    pub name: String,
+    /// The name of the alternative implementation, or `None` for the reference implementation.
+    pub alternative: Option<String>,
+    pub status: Status,
+    pub result: Option<Answer>,
+    pub solution: Option<Answer>,
+    pub duration: Option<Duration>,
+    /// The duration of an earlier run to compare against, see [`RunnableRunOk::baseline_change`].
+    pub baseline: Option<Duration>,
+    pub baseline_change: Option<f64>,
+    pub error: Option<String>,
+}
+impl Record {
+    /// Create the record of the reference implementation of a part, which fails if any of the alternatives disagree with it.
+    pub fn new(day: &str, name: String, run: &PartRun) -> Self {
+        let alternatives_agree = run.alternatives_agree();
+        let PartRun {
+            part,
+            solution,
+            run,
+            ..
+        } = run;
+        let part = *part;
+        let status = match Status::of(run) {
+            Status::Pass | Status::Unverified if !alternatives_agree => Status::Fail,
+            status => status,
+        };
+        match run {
+            Ok(run) => Self {
+                day: day.to_string(),
+                part,
+                name,
+                alternative: None,
+                status,
+                result: Some(run.result.clone()),
+                solution: run.solution.clone().map(Answer::from),
+                duration: Some(run.duration),
+                baseline: run.baseline,
+                baseline_change: run.baseline_change(),
+                error: None,
+            },
+            Err(err) => Self {
+                day: day.to_string(),
+                part,
+                name,
+                alternative: None,
+                status,
+                result: None,
+                solution: solution.clone().map(Answer::from),
+                duration: None,
+                baseline: None,
+                baseline_change: None,
+                error: Some(err.to_string()),
+            },
+        }
+    }
+
+    /// Create the records of the alternative implementations of a part, which are compared with [`PartRun::alternative_solution`].
+    pub fn alternatives(day: &str, name: &str, run: &PartRun) -> Vec<Self> {
+        let solution = run.alternative_solution();
+        run.alternatives
+            .iter()
+            .map(|(alternative, alternative_run)| {
+                let run = PartRun {
+                    part: run.part,
+                    solution: solution.clone(),
+                    run: alternative_run.clone(),
+                    alternatives: Vec::new(),
+                };
+                Self {
+                    alternative: Some(alternative.clone()),
+                    ..Self::new(day, format!("{name} ({alternative})"), &run)
+                }
+            })
+            .collect()
+    }
+
+    /// Create a record for a part that could not be run because the day as a whole failed (e.g. because the input could not be read).
+    pub fn failed(day: &str, part: usize, name: String, error: &str) -> Self {
+        Self {
+            day: day.to_string(),
+            part,
+            name,
+            alternative: None,
+            status: Status::Error,
+            result: None,
+            solution: None,
+            duration: None,
+            baseline: None,
+            baseline_change: None,
+            error: Some(error.to_string()),
+        }
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut result = String::with_capacity(value.len() + 2);
+    result.push('"');
+    for chr in value.chars() {
+        match chr {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            chr if chr.is_control() => write!(result, "\\u{:04x}", chr as u32).unwrap(),
+            chr => result.push(chr),
+        }
+    }
+    result.push('"');
+    result
+}
+
+fn json_option(value: Option<&str>) -> String {
+    value.map_or("null".to_string(), json_string)
+}
+
+/// Format an answer as a JSON number or string depending on its type.
+fn json_answer(answer: Option<&Answer>) -> String {
+    match answer {
+        Some(Answer::Integer(value)) => value.to_string(),
+        Some(Answer::Text(value)) => json_string(value),
+        None => "null".to_string(),
+    }
+}
+
+fn answer_text(answer: Option<&Answer>) -> Cow<'_, str> {
+    answer.map_or(Cow::Borrowed(""), Answer::as_text)
+}
+
+pub fn to_json(records: &[Record]) -> String {
+    let mut result = "[".to_string();
+    for (i, record) in records.iter().enumerate() {
+        if i > 0 {
+            result.push(',');
+        }
+        write!(
+            result,
+            "\n  {{\"day\": {}, \"part\": {}, \"name\": {}, \"alternative\": {}, \"status\": {}, \"result\": {}, \"solution\": {}, \"duration_ns\": {}, \"baseline_ns\": {}, \"baseline_change\": {}, \"error\": {}}}",
+            json_string(&record.day),
+            record.part,
+            json_string(&record.name),
+            json_option(record.alternative.as_deref()),
+            json_string(record.status.as_str()),
+            json_answer(record.result.as_ref()),
+            json_answer(record.solution.as_ref()),
+            record
+                .duration
+                .map_or("null".to_string(), |d| d.as_nanos().to_string()),
+            record
+                .baseline
+                .map_or("null".to_string(), |d| d.as_nanos().to_string()),
+            record
+                .baseline_change
+                .map_or("null".to_string(), |c| c.to_string()),
+            json_option(record.error.as_deref()),
+        )
+        .unwrap();
+    }
+    result.push_str("\n]\n");
+    result
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+pub fn to_csv(records: &[Record]) -> String {
+    let mut result =
+        "day,part,name,alternative,status,result,solution,duration_ns,baseline_ns,baseline_change,error\n"
+            .to_string();
+    for record in records {
+        let fields = [
+            csv_field(&record.day),
+            record.part.to_string(),
+            csv_field(&record.name),
+            csv_field(record.alternative.as_deref().unwrap_or_default()),
+            record.status.as_str().to_string(),
+            csv_field(&answer_text(record.result.as_ref())),
+            csv_field(&answer_text(record.solution.as_ref())),
+            record
+                .duration
+                .map(|d| d.as_nanos().to_string())
+                .unwrap_or_default(),
+            record
+                .baseline
+                .map(|d| d.as_nanos().to_string())
+                .unwrap_or_default(),
+            record
+                .baseline_change
+                .map(|c| c.to_string())
+                .unwrap_or_default(),
+            csv_field(record.error.as_deref().unwrap_or_default()),
+        ];
+        result.push_str(&fields.join(","));
+        result.push('\n');
+    }
+    result
+}
+
+/// Group consecutive records by the day they belong to.
+fn group_by_day(records: &[Record]) -> Vec<(&str, Vec<&Record>)> {
+    let mut days: Vec<(&str, Vec<&Record>)> = Vec::new();
+    for record in records {
+        match days.last_mut() {
+            Some((day, day_records)) if *day == record.day => day_records.push(record),
+            _ => days.push((&record.day, vec![record])),
+        }
+    }
+    days
+}
+
+fn xml_escape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    for chr in value.chars() {
+        match chr {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&apos;"),
+            '\n' | '\r' | '\t' => result.push(chr),
+            // These are not allowed in XML 1.0 at all, not even as character references.
+            '\0'..='\x1f' | '\u{fffe}' | '\u{ffff}' => result.push(char::REPLACEMENT_CHARACTER),
+            chr if chr.is_control() => write!(result, "&#{};", chr as u32).unwrap(),
+            chr => result.push(chr),
+        }
+    }
+    result
+}
+
+fn junit_time(records: &[&Record]) -> String {
+    let total: Duration = records.iter().filter_map(|r| r.duration).sum();
+    format!("{:.6}", total.as_secs_f64())
+}
+
+fn junit_counts(records: &[&Record]) -> String {
+    let count = |status| records.iter().filter(|r| r.status == status).count();
+    format!(
+        "tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" time=\"{}\"",
+        records.len(),
+        count(Status::Fail),
+        count(Status::ReturnedError) + count(Status::Error),
+        count(Status::Missing),
+        junit_time(records),
+    )
+}
+
+/// Render the records as a `JUnit` XML report, with a testsuite per day and a testcase per part and alternative implementation.
+pub fn to_junit(records: &[Record]) -> String {
+    let mut result = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n".to_string();
+    let all: Vec<&Record> = records.iter().collect();
+    writeln!(result, "<testsuites name=\"aoc\" {}>", junit_counts(&all)).unwrap();
+    for (day, records) in group_by_day(records) {
+        writeln!(
+            result,
+            "  <testsuite name=\"{}\" {}>",
+            xml_escape(day),
+            junit_counts(&records),
+        )
+        .unwrap();
+        for record in records {
+            write!(
+                result,
+                "    <testcase name=\"{}\" classname=\"{}\" time=\"{}\"",
+                xml_escape(&record.name),
+                xml_escape(day),
+                junit_time(&[record]),
+            )
+            .unwrap();
+            let error = xml_escape(record.error.as_deref().unwrap_or_default());
+            match record.status {
+                Status::Pass | Status::Unverified => {
+                    result.push_str(">\n");
+                }
+                Status::Fail => {
+                    writeln!(
+                        result,
+                        ">\n      <failure message=\"Result does not match the expected solution.\">Expected:\n{}\nActual:\n{}</failure>",
+                        xml_escape(&answer_text(record.solution.as_ref())),
+                        xml_escape(&answer_text(record.result.as_ref())),
+                    )
+                    .unwrap();
+                }
+                Status::Missing => {
+                    writeln!(result, ">\n      <skipped message=\"{error}\"/>").unwrap();
+                }
+                Status::ReturnedError | Status::Error => {
+                    writeln!(result, ">\n      <error message=\"{error}\"/>").unwrap();
+                }
+            }
+            if let Some(output) = &record.result {
+                writeln!(
+                    result,
+                    "      <system-out>{}</system-out>",
+                    xml_escape(&output.as_text())
+                )
+                .unwrap();
+            }
+            result.push_str("    </testcase>\n");
+        }
+        result.push_str("  </testsuite>\n");
+    }
+    result.push_str("</testsuites>\n");
+    result
+}
+
+/// The markers between which [`update_markdown_section`] places the results table.
+pub const MARKDOWN_SECTION_START: &str = "<!-- results -->";
+pub const MARKDOWN_SECTION_END: &str = "<!-- /results -->";
+
+fn markdown_cell(record: Option<&&Record>) -> String {
+    let Some(record) = record else {
+        return String::new();
+    };
+    let duration = record
+        .duration
+        .map(|d| format!("{d:?}"))
+        .unwrap_or_default();
+    match record.status {
+        Status::Pass => format!("✓ {duration}"),
+        Status::Fail => format!("✗ {duration}"),
+        Status::Unverified => duration,
+        Status::Missing => "-".to_string(),
+        Status::ReturnedError => "errored".to_string(),
+        Status::Error => "error".to_string(),
+    }
+}
+
+/// Render the records as a Markdown table with a row per day and a column per part, followed by the totals. Alternative implementations are left out.
+pub fn to_markdown(records: &[Record], summary: &Summary) -> String {
+    let parts = records.iter().map(|r| r.part).max().unwrap_or(0);
+    let mut result = "| Day |".to_string();
+    for part in 1..=parts {
+        write!(result, " Part {part} |").unwrap();
+    }
+    result.push_str("\n| --- |");
+    result.push_str(&" --- |".repeat(parts));
+    result.push('\n');
+    for (day, records) in group_by_day(records) {
+        write!(result, "| {day} |").unwrap();
+        for part in 1..=parts {
+            let record = records
+                .iter()
+                .find(|r| r.part == part && r.alternative.is_none());
+            write!(result, " {} |", markdown_cell(record)).unwrap();
+        }
+        result.push('\n');
+    }
+    writeln!(
+        result,
+        "\nRan {} parts in {:?}, averaging {:?} per part.",
+        summary.count, summary.total, summary.average,
+    )
+    .unwrap();
+    result
+}
+
+/// Replace the contents between the section markers in a Markdown document.
+pub fn update_markdown_section(contents: &str, section: &str) -> Result<String, String> {
+    let missing =
+        || format!("Missing '{MARKDOWN_SECTION_START}' and '{MARKDOWN_SECTION_END}' markers.");
+    let (before, rest) = contents
+        .split_once(MARKDOWN_SECTION_START)
+        .ok_or_else(missing)?;
+    let (_, after) = rest.split_once(MARKDOWN_SECTION_END).ok_or_else(missing)?;
+    Ok(format!(
+        "{before}{MARKDOWN_SECTION_START}\n{section}{MARKDOWN_SECTION_END}{after}"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::runner::RunnableRunOk;
+
+    fn part_run(part: usize, solution: Option<&str>, run: RunnableRun) -> PartRun {
+        PartRun {
+            part,
+            solution: solution.map(str::to_string),
+            run,
+            alternatives: Vec::new(),
+        }
+    }
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record::new(
+                "day01",
+                "Day 01 part 1".to_string(),
+                &part_run(
+                    1,
+                    Some("24000"),
+                    Ok(RunnableRunOk {
+                        result: Answer::Integer(24000),
+                        solution: Some("24000".to_string()),
+                        duration: Duration::from_micros(12),
+                        stats: None,
+                        parse_duration: None,
+                        baseline: Some(Duration::from_micros(24)),
+                        memory: None,
+                    }),
+                ),
+            ),
+            Record::new(
+                "day01",
+                "Day 01 part 2".to_string(),
+                &part_run(
+                    2,
+                    None,
+                    Ok(RunnableRunOk {
+                        result: Answer::Text("a,\"b\"\nc".to_string()),
+                        solution: None,
+                        duration: Duration::from_millis(3),
+                        stats: None,
+                        parse_duration: None,
+                        baseline: None,
+                        memory: None,
+                    }),
+                ),
+            ),
+            Record::new(
+                "day02",
+                "Day 02 part 1".to_string(),
+                &part_run(1, None, Err(RunnableRunErr::Missing)),
+            ),
+            Record::failed("day03", 1, "Day 03 part 1".to_string(), "No input."),
+        ]
+    }
+
+    #[test]
+    fn failed_record_keeps_solution() {
+        let record = Record::new(
+            "day01",
+            "Day 01 part 1".to_string(),
+            &part_run(
+                1,
+                Some("24000"),
+                Err(RunnableRunErr::Panicked("Panicked.".to_string())),
+            ),
+        );
+        assert_eq!(record.solution, Some(Answer::Integer(24000)));
+        assert_eq!(record.error.as_deref(), Some("Panicked."));
+    }
+
+    #[test]
+    fn alternative_records() {
+        let alternative = |result: i128| {
+            Ok(RunnableRunOk {
+                result: Answer::Integer(result),
+                solution: Some("24000".to_string()),
+                duration: Duration::from_micros(5),
+                stats: None,
+                parse_duration: None,
+                baseline: None,
+                memory: None,
+            })
+        };
+        let run = PartRun {
+            alternatives: vec![
+                ("fast".to_string(), alternative(24000)),
+                ("wrong".to_string(), alternative(1)),
+            ],
+            ..part_run(
+                1,
+                Some("24000"),
+                Err(RunnableRunErr::Panicked("Panicked.".to_string())),
+            )
+        };
+
+        let record = Record::new("day01", "Day 01 part 1".to_string(), &run);
+        assert_eq!(record.status, Status::Error);
+        let records: Vec<(String, Option<String>, Status)> =
+            Record::alternatives("day01", "Day 01 part 1", &run)
+                .into_iter()
+                .map(|record| (record.name, record.alternative, record.status))
+                .collect();
+        assert_eq!(
+            records,
+            vec![
+                (
+                    "Day 01 part 1 (fast)".to_string(),
+                    Some("fast".to_string()),
+                    Status::Pass
+                ),
+                (
+                    "Day 01 part 1 (wrong)".to_string(),
+                    Some("wrong".to_string()),
+                    Status::Fail
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn xml_escape_invalid_characters() {
+        assert_eq!(
+            xml_escape("a\u{1b}[31m<b>\tc\u{0}\u{85}"),
+            "a\u{fffd}[31m&lt;b&gt;\tc\u{fffd}&#133;"
+        );
+    }
+
+    #[test]
+    fn status() {
+        let statuses: Vec<Status> = records().into_iter().map(|r| r.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                Status::Pass,
+                Status::Unverified,
+                Status::Missing,
+                Status::Error
+            ]
+        );
+        assert_eq!(
+            Status::of(&Err(RunnableRunErr::ReturnedError(
+                "Invalid input.".to_string()
+            ))),
+            Status::ReturnedError
+        );
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(
+            to_json(&records()),
+            [
+                "[",
+                r#"  {"day": "day01", "part": 1, "name": "Day 01 part 1", "alternative": null, "status": "pass", "result": 24000, "solution": 24000, "duration_ns": 12000, "baseline_ns": 24000, "baseline_change": -0.5, "error": null},"#,
+                r#"  {"day": "day01", "part": 2, "name": "Day 01 part 2", "alternative": null, "status": "unverified", "result": "a,\"b\"\nc", "solution": null, "duration_ns": 3000000, "baseline_ns": null, "baseline_change": null, "error": null},"#,
+                r#"  {"day": "day02", "part": 1, "name": "Day 02 part 1", "alternative": null, "status": "missing", "result": null, "solution": null, "duration_ns": null, "baseline_ns": null, "baseline_change": null, "error": "Not implemented."},"#,
+                r#"  {"day": "day03", "part": 1, "name": "Day 03 part 1", "alternative": null, "status": "error", "result": null, "solution": null, "duration_ns": null, "baseline_ns": null, "baseline_change": null, "error": "No input."}"#,
+                "]",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn csv() {
+        assert_eq!(
+            to_csv(&records()),
+            [
+                "day,part,name,alternative,status,result,solution,duration_ns,baseline_ns,baseline_change,error",
+                "day01,1,Day 01 part 1,,pass,24000,24000,12000,24000,-0.5,",
+                "day01,2,Day 01 part 2,,unverified,\"a,\"\"b\"\"\nc\",,3000000,,,",
+                "day02,1,Day 02 part 1,,missing,,,,,,Not implemented.",
+                "day03,1,Day 03 part 1,,error,,,,,,No input.",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn summary() {
+        assert_eq!(
+            Summary::of([Duration::from_millis(3), Duration::from_millis(5)]),
+            Summary {
+                count: 2,
+                total: Duration::from_millis(8),
+                average: Duration::from_millis(4),
+            }
+        );
+        assert_eq!(Summary::of([]), Summary::default());
+    }
+
+    #[test]
+    fn markdown() {
+        let records = records();
+        let summary = Summary::of(records.iter().filter_map(|r| r.duration));
+        assert_eq!(
+            to_markdown(&records, &summary),
+            [
+                "| Day | Part 1 | Part 2 |",
+                "| --- | --- | --- |",
+                "| day01 | ✓ 12µs | 3ms |",
+                "| day02 | - |  |",
+                "| day03 | error |  |",
+                "",
+                "Ran 2 parts in 3.012ms, averaging 1.506ms per part.",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn markdown_section() {
+        assert_eq!(
+            update_markdown_section(
+                "# Title\n<!-- results -->\nold\n<!-- /results -->\nrest\n",
+                "new\n"
+            ),
+            Ok("# Title\n<!-- results -->\nnew\n<!-- /results -->\nrest\n".to_string())
+        );
+        assert!(update_markdown_section("# Title\n", "new\n").is_err());
+    }
+
+    #[test]
+    fn junit() {
+        let mut records = records();
+        records[1].solution = Some(Answer::Text("<d>".to_string()));
+        records[1].status = Status::Fail;
+        assert_eq!(
+            to_junit(&records),
+            [
+                r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+                r#"<testsuites name="aoc" tests="4" failures="1" errors="1" skipped="1" time="0.003012">"#,
+                r#"  <testsuite name="day01" tests="2" failures="1" errors="0" skipped="0" time="0.003012">"#,
+                r#"    <testcase name="Day 01 part 1" classname="day01" time="0.000012">"#,
+                "      <system-out>24000</system-out>",
+                "    </testcase>",
+                r#"    <testcase name="Day 01 part 2" classname="day01" time="0.003000">"#,
+                r#"      <failure message="Result does not match the expected solution.">Expected:"#,
+                "&lt;d&gt;",
+                "Actual:",
+                "a,&quot;b&quot;",
+                "c</failure>",
+                "      <system-out>a,&quot;b&quot;",
+                "c</system-out>",
+                "    </testcase>",
+                "  </testsuite>",
+                r#"  <testsuite name="day02" tests="1" failures="0" errors="0" skipped="1" time="0.000000">"#,
+                r#"    <testcase name="Day 02 part 1" classname="day02" time="0.000000">"#,
+                r#"      <skipped message="Not implemented."/>"#,
+                "    </testcase>",
+                "  </testsuite>",
+                r#"  <testsuite name="day03" tests="1" failures="0" errors="1" skipped="0" time="0.000000">"#,
+                r#"    <testcase name="Day 03 part 1" classname="day03" time="0.000000">"#,
+                r#"      <error message="No input."/>"#,
+                "    </testcase>",
+                "  </testsuite>",
+                "</testsuites>",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+}
@@ -1,16 +1,59 @@
 use std::{
-    env, fs,
+    borrow::Borrow,
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+    env,
+    fmt::Display,
+    fs, hint,
+    io::{self, IsTerminal, Write},
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    process,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, AtomicU8, AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError},
+        Arc, Mutex, Once,
+    },
+    thread,
     time::{Duration, Instant},
 };
 
-use ansi_term::{
-    unstyle, ANSIStrings,
-    Colour::{Blue, Cyan, Green, Purple, Red},
+use ansi_term::{unstyle, ANSIStrings};
+
+use crate::{
+    answers::{get_answers_path, save_answers, Answers},
+    colour::{self, Blue, Cyan, Green, Purple, Red},
+    diff,
+    memory::{self, AllocationStats},
+    ocr,
+    solution::{registered_solutions, Answer},
+    watch,
 };
 
+/// The value returned by a part, which is either the answer itself or a [`Result`] with the answer or an error.
+///
+/// The marker type `M` only serves to keep the implementations for answers and results apart, and is inferred.
+pub trait PartOutput<M> {
+    fn into_answer(self) -> Result<Answer, String>;
+}
+/// Marker for parts that return their answer directly.
+pub struct Plain;
+/// Marker for parts that return a [`Result`] with their answer.
+pub struct Fallible;
+impl<T: Display + 'static> PartOutput<Plain> for T {
+    fn into_answer(self) -> Result<Answer, String> {
+        Ok(Answer::of(self))
+    }
+}
+impl<T: Display + 'static, E: Display> PartOutput<Fallible> for Result<T, E> {
+    fn into_answer(self) -> Result<Answer, String> {
+        self.map(Answer::of).map_err(|err| err.to_string())
+    }
+}
+
 pub enum Runnable<T, F>
 where
-    T: ToString,
     F: Fn(&str) -> T,
 {
     Implemented(F),
@@ -18,47 +61,395 @@ where
 }
 impl<F, T> From<F> for Runnable<T, F>
 where
-    T: ToString,
     F: Fn(&str) -> T,
 {
     fn from(value: F) -> Self {
         Runnable::Implemented(value)
     }
 }
+impl<T, F> Runnable<T, F>
+where
+    F: Fn(&str) -> T + Clone + Send + Sync + 'static,
+{
+    fn to_part_fn<M>(&self) -> Option<PartFn<str>>
+    where
+        T: PartOutput<M>,
+    {
+        match self {
+            Runnable::Implemented(part) => Some(part_fn(part.clone())),
+            Runnable::Missing => None,
+        }
+    }
+}
 impl From<()> for Runnable<String, fn(&str) -> String> {
     fn from(_value: ()) -> Self {
         Runnable::Missing
     }
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct DurationStats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+impl DurationStats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let count = sorted.len();
+        assert!(count > 0, "Cannot calculate statistics without samples.");
+
+        let median = if count % 2 == 0 {
+            (sorted[count / 2 - 1] + sorted[count / 2]) / 2
+        } else {
+            sorted[count / 2]
+        };
+        let mean = sorted.iter().sum::<Duration>() / count as u32;
+        let variance = sorted
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / f64::from(count as u32);
+
+        Self {
+            samples: count,
+            min: sorted[0],
+            median,
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct RunnableRunOk {
-    pub result: String,
+    pub result: Answer,
     pub solution: Option<String>,
+    /// The duration of the run. When benchmarking this is the median of all samples.
     pub duration: Duration,
+    pub stats: Option<DurationStats>,
+    /// The time spent parsing the input, for days where this is done separately from the parts.
+    pub parse_duration: Option<Duration>,
+    /// The duration of an earlier run to compare against.
+    pub baseline: Option<Duration>,
+    /// The memory usage of the run, if allocation tracking is enabled.
+    pub memory: Option<AllocationStats>,
+}
+impl RunnableRunOk {
+    /// Whether the result matches the expected solution, or there is no expected solution to compare it with.
+    pub fn matches_solution(&self) -> bool {
+        self.solution.as_ref().map_or(true, |solution| {
+            diff::matches(&self.result.as_text(), solution)
+        })
+    }
+
+    /// The relative change in duration compared to the baseline, e.g. `0.5` if the run took 50% longer.
+    ///
+    /// This is `None` if there is no baseline or if the baseline is zero, as there is no meaningful relative change then.
+    pub fn baseline_change(&self) -> Option<f64> {
+        self.baseline
+            .filter(|baseline| !baseline.is_zero())
+            .map(|baseline| self.duration.as_secs_f64() / baseline.as_secs_f64() - 1.0)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum RunnableRunErr {
+    Missing,
+    /// The part returned an error, e.g. because the input is invalid.
+    ReturnedError(String),
+    Panicked(String),
+    TimedOut(Duration),
+}
+impl Display for RunnableRunErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RunnableRunErr::Missing => f.write_str("Not implemented."),
+            RunnableRunErr::ReturnedError(message) => write!(f, "Error: {message}"),
+            RunnableRunErr::Panicked(message) => f.write_str(message),
+            RunnableRunErr::TimedOut(timeout) => write!(f, "Timed out after {timeout:?}."),
+        }
+    }
+}
+
+pub type RunnableRun = Result<RunnableRunOk, RunnableRunErr>;
+
+/// The run of a single part of a day for a single input.
+#[derive(Clone)]
+pub struct PartRun {
+    pub part: usize,
+    /// The expected solution of the part, which is also known if the run failed.
+    pub solution: Option<String>,
+    pub run: RunnableRun,
+    /// The runs of the alternative implementations of the part, by name.
+    pub alternatives: Vec<(String, RunnableRun)>,
+}
+impl PartRun {
+    /// The result the alternative implementations are expected to produce: that of the reference implementation if it succeeded, or the expected solution otherwise.
+    pub fn alternative_solution(&self) -> Option<String> {
+        match &self.run {
+            Ok(run) => Some(run.result.to_string()),
+            Err(_) => self.solution.clone(),
+        }
+    }
+
+    /// Whether all alternative implementations produced the expected result, see [`PartRun::alternative_solution`].
+    pub fn alternatives_agree(&self) -> bool {
+        alternatives_agree(&self.alternatives)
+    }
 }
 
-pub type RunnableRun = Result<RunnableRunOk, String>;
+fn alternatives_agree(alternatives: &[(String, RunnableRun)]) -> bool {
+    alternatives
+        .iter()
+        .all(|(_, run)| run.as_ref().is_ok_and(RunnableRunOk::matches_solution))
+}
+
+#[derive(Clone)]
+pub struct BenchOptions {
+    /// The maximum number of timed runs.
+    pub iterations: usize,
+    /// The time after which no new runs will be started, even if the number of iterations hasn't been reached yet.
+    pub budget: Duration,
+}
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            iterations: 100,
+            budget: Duration::from_secs(5),
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct RunnableOptions {
+    /// The maximum time a single part may take. If this is exceeded the run is abandoned and reported as timed out. When benchmarking this only applies to the first run.
+    pub timeout: Option<Duration>,
+    /// Run each part repeatedly to get more reliable timings. The first run is used to determine the result and as a warm-up, and is not included in the statistics.
+    pub bench: Option<BenchOptions>,
+    /// Save the results of the parts that don't have an expected solution yet as their solution.
+    pub save: bool,
+    /// Values for the parameters of the parts, see [`param`]. These take precedence over the parameters in the answers file.
+    pub params: BTreeMap<String, String>,
+}
+impl RunnableOptions {
+    /// Handle a command line argument that configures the options, consuming its value from `args` if needed.
+    ///
+    /// Returns `false` if the argument is not an option that applies here.
+    pub fn parse_arg(
+        &mut self,
+        arg: &str,
+        args: &mut impl Iterator<Item = String>,
+    ) -> Result<bool, String> {
+        let mut value = || args.next().ok_or(format!("Missing value for {arg}."));
+        match arg {
+            "--timeout" => {
+                self.timeout = Some(parse_duration(&value()?)?);
+            }
+            "--save" => {
+                self.save = true;
+            }
+            "--param" => {
+                let value = value()?;
+                let (name, value) = value
+                    .split_once('=')
+                    .ok_or(format!("Invalid parameter '{value}', expected name=value."))?;
+                self.params.insert(name.to_string(), value.to_string());
+            }
+            "--bench" => {
+                self.bench.get_or_insert_with(BenchOptions::default);
+            }
+            "--bench-iterations" => {
+                let value = value()?;
+                self.bench
+                    .get_or_insert_with(BenchOptions::default)
+                    .iterations = value
+                    .parse()
+                    .ok()
+                    .filter(|iterations| *iterations > 0)
+                    .ok_or(format!("Invalid number of iterations '{value}'."))?;
+            }
+            "--bench-budget" => {
+                self.bench.get_or_insert_with(BenchOptions::default).budget =
+                    parse_duration(&value()?)?;
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+}
+
+/// The default relative change compared to the baseline at which a run is considered to have regressed.
+pub const REGRESSION_DEFAULT: f64 = 0.1;
 
 pub struct DurationThresholds {
     pub good: Duration,
     pub acceptable: Duration,
+    /// The relative change compared to the baseline at which a run is considered to have regressed (or improved).
+    pub regression: f64,
 }
-const THRESHOLDS_DEFAULT: DurationThresholds = DurationThresholds {
-    good: Duration::from_millis(1),
-    acceptable: Duration::from_secs(1),
-};
 
+/// How the thresholds for colouring durations are determined.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Thresholds {
+    /// Fixed durations.
+    Absolute {
+        good: Duration,
+        acceptable: Duration,
+    },
+    /// Fractions of the average duration of the parts being reported.
+    Relative { good: f64, acceptable: f64 },
+}
+impl Thresholds {
+    pub const ABSOLUTE_DEFAULT: Self = Self::Absolute {
+        good: Duration::from_millis(1),
+        acceptable: Duration::from_secs(1),
+    };
+    pub const RELATIVE_DEFAULT: Self = Self::Relative {
+        good: 1.0 / 3.0,
+        acceptable: 2.0 / 3.0,
+    };
+
+    pub fn for_average(&self, average: Duration, regression: f64) -> DurationThresholds {
+        let (good, acceptable) = match *self {
+            Self::Absolute { good, acceptable } => (good, acceptable),
+            Self::Relative { good, acceptable } => {
+                (average.mul_f64(good), average.mul_f64(acceptable))
+            }
+        };
+        DurationThresholds {
+            good,
+            acceptable,
+            regression,
+        }
+    }
+}
+
+/// The environment variables that can be used to configure the thresholds, in the same format as the matching command line options.
+const THRESHOLDS_ENV: [&str; 3] = [
+    "AOC_THRESHOLDS",
+    "AOC_THRESHOLD_GOOD",
+    "AOC_THRESHOLD_ACCEPTABLE",
+];
+
+/// The thresholds for colouring durations as configured by the user, which are only interpreted once all options are known.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ThresholdOptions {
+    pub mode: Option<String>,
+    pub good: Option<String>,
+    pub acceptable: Option<String>,
+}
+impl ThresholdOptions {
+    /// Read the options from the environment. These can then be overridden using [`ThresholdOptions::parse_arg`].
+    pub fn from_env() -> Self {
+        let [mode, good, acceptable] = THRESHOLDS_ENV.map(|name| env::var(name).ok());
+        Self {
+            mode,
+            good,
+            acceptable,
+        }
+    }
+
+    /// Handle the threshold related command line arguments, returning whether the argument was one of them.
+    pub fn parse_arg(
+        &mut self,
+        arg: &str,
+        args: &mut impl Iterator<Item = String>,
+    ) -> Result<bool, String> {
+        let field = match arg {
+            "--thresholds" => &mut self.mode,
+            "--threshold-good" => &mut self.good,
+            "--threshold-acceptable" => &mut self.acceptable,
+            _ => return Ok(false),
+        };
+        *field = Some(
+            args.next()
+                .ok_or_else(|| format!("Missing value for {arg}."))?,
+        );
+        Ok(true)
+    }
+
+    /// Get the thresholds. If no mode was chosen it follows from the thresholds that are set, or the given mode is used if they don't imply one. Thresholds that are not set use the defaults for the mode.
+    pub fn resolve(&self, default: Thresholds) -> Result<Thresholds, String> {
+        let mode = match self.mode.as_deref() {
+            None => {
+                let mut implied = [&self.good, &self.acceptable]
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|value| implied_mode(value));
+                let mode = implied.next().unwrap_or(default);
+                if implied.any(|other| other != mode) {
+                    return Err("Thresholds cannot mix a percentage and a duration.".to_string());
+                }
+                mode
+            }
+            Some("absolute") => Thresholds::ABSOLUTE_DEFAULT,
+            Some("relative") => Thresholds::RELATIVE_DEFAULT,
+            Some(mode) => return Err(format!("Unknown thresholds mode '{mode}'.")),
+        };
+        Ok(match mode {
+            Thresholds::Absolute { good, acceptable } => Thresholds::Absolute {
+                good: self.good.as_deref().map_or(Ok(good), parse_duration)?,
+                acceptable: self
+                    .acceptable
+                    .as_deref()
+                    .map_or(Ok(acceptable), parse_duration)?,
+            },
+            Thresholds::Relative { good, acceptable } => Thresholds::Relative {
+                good: self.good.as_deref().map_or(Ok(good), parse_percentage)?,
+                acceptable: self
+                    .acceptable
+                    .as_deref()
+                    .map_or(Ok(acceptable), parse_percentage)?,
+            },
+        })
+    }
+}
+
+/// Get the mode implied by the value of a threshold: a percentage is relative, while a duration with a unit is absolute.
+fn implied_mode(value: &str) -> Option<Thresholds> {
+    if value.ends_with('%') {
+        Some(Thresholds::RELATIVE_DEFAULT)
+    } else if value.ends_with(char::is_alphabetic) {
+        Some(Thresholds::ABSOLUTE_DEFAULT)
+    } else {
+        None
+    }
+}
+
+/// Describe why a successful run failed, so that this doesn't rely on colour alone (which is lost without a terminal or with `NO_COLOR`).
+fn get_problems(run: &RunnableRunOk, alternatives: &[(String, RunnableRun)]) -> Vec<String> {
+    let mut problems = Vec::new();
+    if let Some(solution) = run.solution.as_deref().filter(|_| !run.matches_solution()) {
+        let solution = solution.trim_end();
+        problems.push(if solution.contains('\n') {
+            "wrong".to_string()
+        } else {
+            format!("wrong, should be {solution}")
+        });
+    }
+    if !alternatives_agree(alternatives) {
+        problems.push("alternatives disagree".to_string());
+    }
+    problems
+}
+
+/// Print the run of a part, followed by a comparison with the runs of its alternative implementations (if any).
 pub fn print_runnable_run(
     name: String,
     run: RunnableRun,
+    alternatives: &[(String, RunnableRun)],
     thresholds: &DurationThresholds,
     show_result: bool,
 ) {
     let name = Purple.paint(name);
     match run {
         Err(err) => {
-            println!("> {name}: {}", Red.paint(err));
+            println!("> {name}: {}", Red.paint(err.to_string()));
+            print_alternatives(None, alternatives);
         }
         Ok(run) => {
             let duration_colour = if run.duration < thresholds.good {
@@ -68,29 +459,72 @@ pub fn print_runnable_run(
             } else {
                 Red
             };
-            let duration_formatted = duration_colour.paint(format!("{:?}", run.duration));
+            let mut duration_formatted = duration_colour
+                .paint(format!("{:?}", run.duration))
+                .to_string();
+            if let Some(stats) = &run.stats {
+                duration_formatted = format!(
+                    "median {duration_formatted}, min {:?}, mean {:?} ± {:?}, {} runs",
+                    stats.min, stats.mean, stats.stddev, stats.samples,
+                );
+            }
+            if let Some(parse_duration) = run.parse_duration {
+                duration_formatted =
+                    format!("parse {parse_duration:?}, solve {duration_formatted}");
+            }
+            if let (Some(baseline), Some(change)) = (run.baseline, run.baseline_change()) {
+                let change_formatted = format!("{:+.0}% vs {baseline:?}", change * 100.0);
+                let change_formatted = if change > thresholds.regression {
+                    Red.paint(change_formatted).to_string()
+                } else if change < -thresholds.regression {
+                    Green.paint(change_formatted).to_string()
+                } else {
+                    change_formatted
+                };
+                duration_formatted = format!("{duration_formatted}, {change_formatted}");
+            }
+            if let Some(memory) = run.memory {
+                duration_formatted = format!("{duration_formatted}, {memory}");
+            }
 
             if !show_result {
-                let name = if run.solution.map_or(true, |s| s == run.result) {
-                    name
+                let problems = get_problems(&run, alternatives);
+                if problems.is_empty() {
+                    println!("> {name} [{duration_formatted}]");
                 } else {
-                    Red.paint(unstyle(&ANSIStrings(&[name])))
-                };
-                println!("> {name} [{duration_formatted}]");
+                    println!(
+                        "> {} [{duration_formatted}] ({})",
+                        Red.paint(unstyle(&ANSIStrings(&[name]))),
+                        problems.join("; "),
+                    );
+                }
+                print_alternatives(Some(run.duration), alternatives);
                 return;
             }
 
-            let result_formatted = match run.solution {
+            let result = run.result.as_text();
+
+            let result_formatted = match run.solution.clone() {
                 Some(expected) => {
-                    if run.result == expected {
-                        Green.paint(&run.result).to_string()
-                    } else if run.result.contains('\n') || expected.contains('\n') {
-                        format!("{}\nShould be:\n{expected}", Red.paint(&run.result))
+                    if diff::matches(&result, &expected) {
+                        Green.paint(&*result).to_string()
+                    } else if let Some(letters) = (!expected.contains('\n'))
+                        .then(|| ocr::recognise_text(&result))
+                        .flatten()
+                    {
+                        format!("{} (should be {expected})", Red.paint(letters))
+                    } else if result.contains('\n') || expected.contains('\n') {
+                        let (row, column) = diff::first_mismatch(&result, &expected).unwrap();
+                        let mut lines = vec![Red
+                            .paint(format!("Mismatch at row {row}, column {column}:"))
+                            .to_string()];
+                        lines.extend(diff::render(&result, &expected));
+                        lines.join("\n")
                     } else {
-                        format!("{} (should be {expected})", Red.paint(&run.result))
+                        format!("{} (should be {expected})", Red.paint(&*result))
                     }
                 }
-                None => run.result.clone(),
+                None => result.to_string(),
             };
 
             if result_formatted.contains('\n') {
@@ -101,41 +535,405 @@ pub fn print_runnable_run(
             } else {
                 println!("> {name}: {result_formatted} [{duration_formatted}]");
             }
+            print_alternatives(Some(run.duration), alternatives);
+        }
+    }
+}
+
+/// Print a comparison of the timings of the alternative implementations of a part with the reference implementation (if it succeeded), flagging the ones that disagree with the expected result.
+fn print_alternatives(reference: Option<Duration>, alternatives: &[(String, RunnableRun)]) {
+    const REFERENCE: &str = "reference";
+    if alternatives.is_empty() {
+        return;
+    }
+    let width = alternatives
+        .iter()
+        .map(|(name, _)| name.len())
+        .chain([REFERENCE.len()])
+        .max()
+        .unwrap();
+    if let Some(reference) = reference {
+        println!("  {REFERENCE:width$}  {reference:?}");
+    }
+    for (name, alternative) in alternatives {
+        match alternative {
+            Ok(alternative) => {
+                let mut line = format!("  {name:width$}  {:?}", alternative.duration);
+                // A reference that was too fast to measure has no meaningful ratio.
+                if let Some(reference) = reference.filter(|reference| !reference.is_zero()) {
+                    let ratio = alternative.duration.as_secs_f64() / reference.as_secs_f64();
+                    line = format!("{line} ({ratio:.2}x)");
+                }
+                if !alternative.matches_solution() {
+                    line = format!(
+                        "{line} {}",
+                        Red.paint(format!("disagrees: {}", alternative.result))
+                    );
+                }
+                println!("{line}");
+            }
+            Err(err) => println!("  {name:width$}  {}", Red.paint(err.to_string())),
         }
     }
 }
 
-fn run_runnable<T, F>(
-    runnable: &Runnable<T, F>,
-    input: &str,
+thread_local! {
+    static CANCELLED: RefCell<Option<Arc<AtomicU8>>> = const { RefCell::new(None) };
+    static PARAMS: RefCell<BTreeMap<String, String>> = const { RefCell::new(BTreeMap::new()) };
+    static CATCHING_PANIC: Cell<bool> = const { Cell::new(false) };
+    static CAUGHT_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+static PANIC_HOOK: Once = Once::new();
+
+/// Install a panic hook that records the message & location of panics that happen inside [`catch_panic`] instead of printing them.
+fn install_panic_hook() {
+    PANIC_HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !CATCHING_PANIC.get() {
+                default_hook(info);
+                return;
+            }
+
+            let payload = info.payload();
+            let message = payload
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                .unwrap_or("Box<dyn Any>");
+            let message = match info.location() {
+                Some(location) => format!("Panicked at {location}: {message}"),
+                None => format!("Panicked: {message}"),
+            };
+            CAUGHT_PANIC.set(Some(message));
+        }));
+    });
+}
+
+/// Run the given function, converting a panic into an error containing the panic message.
+fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, RunnableRunErr> {
+    install_panic_hook();
+    CATCHING_PANIC.set(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING_PANIC.set(false);
+    result.map_err(|_| {
+        RunnableRunErr::Panicked(
+            CAUGHT_PANIC
+                .take()
+                .unwrap_or_else(|| "Panicked.".to_string()),
+        )
+    })
+}
+
+/// Abort the current part if the runner is no longer interested in its result (e.g. because it timed out).
+///
+/// Threads cannot be stopped from the outside, so long-running solutions should call this periodically to avoid wasting CPU time in the background after they have been abandoned.
+pub fn check_cancelled() {
+    let cancelled = CANCELLED.with_borrow(|cancelled| {
+        cancelled
+            .as_ref()
+            .is_some_and(|state| state.load(Ordering::Relaxed) == WORKER_ABANDONED)
+    });
+    assert!(!cancelled, "Cancelled.");
+}
+
+/// Get the value of a parameter of the part that is currently running, or the given default if it has not been set.
+///
+/// This allows parts to use different constants for different inputs (e.g. for the examples from the puzzle text). Parameters can be set for a specific input in its answers file (see [`Answers`]) or for all inputs using `--param name=value`.
+#[track_caller]
+pub fn param<T: FromStr>(name: &str, default: T) -> T {
+    PARAMS_USED.lock().unwrap().insert(name.to_string(), true);
+    let Some(value) = PARAMS.with_borrow(|params| params.get(name).cloned()) else {
+        return default;
+    };
+    let Some(parsed) = value.parse().ok() else {
+        panic!("Invalid value '{value}' for parameter '{name}'.");
+    };
+    parsed
+}
+
+/// The names of all parameters that have been set or read, with whether any part has read them.
+static PARAMS_USED: Mutex<BTreeMap<String, bool>> = Mutex::new(BTreeMap::new());
+
+/// Record the parameters that are set for a run, so that we can warn about the ones that are never read.
+fn register_params(params: &BTreeMap<String, String>) {
+    let mut used = PARAMS_USED.lock().unwrap();
+    for name in params.keys() {
+        used.entry(name.clone()).or_insert(false);
+    }
+}
+
+/// Get the names of the parameters that have been set but were not read by any of the parts that ran.
+pub fn unused_params() -> Vec<String> {
+    PARAMS_USED
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|(_, used)| !**used)
+        .map(|(name, _)| name.clone())
+        .collect()
+}
+
+/// Print a warning for each of the [`unused_params`], as these are most likely typos.
+pub fn warn_unused_params() {
+    for name in unused_params() {
+        eprintln!(
+            "{}",
+            Red.paint_err(format!(
+                "Warning: parameter '{name}' was set but not used by any of the parts that ran."
+            ))
+        );
+    }
+}
+
+pub(crate) const WORKER_STACK_SIZE: usize = 64 * 1024 * 1024;
+
+/// The number of worker threads that timed out but have not finished yet.
+static ABANDONED_WORKERS: AtomicUsize = AtomicUsize::new(0);
+/// Whether a part has been started while an abandoned worker thread was still running.
+static TIMINGS_SKEWED: AtomicBool = AtomicBool::new(false);
+
+const WORKER_RUNNING: u8 = 0;
+const WORKER_FINISHED: u8 = 1;
+const WORKER_ABANDONED: u8 = 2;
+
+/// Whether any part ran while a part that timed out was still running in the background, which makes its timings unreliable.
+pub fn timings_skewed() -> bool {
+    TIMINGS_SKEWED.load(Ordering::Relaxed)
+}
+
+/// Print a warning if [`timings_skewed`].
+pub fn warn_if_timings_skewed() {
+    if timings_skewed() {
+        eprintln!(
+            "{}",
+            Red.paint_err(
+                "Warning: some parts ran while parts that timed out were still running in the background, so their timings may be unreliable."
+            )
+        );
+    }
+}
+
+/// Run the given function, on a worker thread if there is a timeout so that we can stop waiting for it if it exceeds the timeout.
+fn run_on_worker<R>(
+    f: impl FnOnce() -> R + Send + 'static,
+    options: &RunnableOptions,
+) -> Result<R, RunnableRunErr>
+where
+    R: Send + 'static,
+{
+    if ABANDONED_WORKERS.load(Ordering::Relaxed) > 0 {
+        TIMINGS_SKEWED.store(true, Ordering::Relaxed);
+    }
+    register_params(&options.params);
+
+    let Some(timeout) = options.timeout else {
+        let params = PARAMS.replace(options.params.clone());
+        let result = catch_panic(f);
+        PARAMS.set(params);
+        return result;
+    };
+
+    let state = Arc::new(AtomicU8::new(WORKER_RUNNING));
+    let params = options.params.clone();
+    let (sender, receiver) = mpsc::channel();
+    {
+        let state = state.clone();
+        thread::Builder::new()
+            .stack_size(WORKER_STACK_SIZE)
+            .spawn(move || {
+                CANCELLED.set(Some(state.clone()));
+                PARAMS.set(params);
+                let result = catch_panic(f);
+                if state
+                    .compare_exchange(
+                        WORKER_RUNNING,
+                        WORKER_FINISHED,
+                        Ordering::Relaxed,
+                        Ordering::Relaxed,
+                    )
+                    .is_err()
+                {
+                    ABANDONED_WORKERS.fetch_sub(1, Ordering::Relaxed);
+                }
+                // The receiver is gone if we timed out, in which case nobody cares about the result anymore.
+                let _ = sender.send(result);
+            })
+            .expect("Unable to spawn worker thread.");
+    }
+
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(RecvTimeoutError::Timeout) => {
+            if state
+                .compare_exchange(
+                    WORKER_RUNNING,
+                    WORKER_ABANDONED,
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                )
+                .is_ok()
+            {
+                ABANDONED_WORKERS.fetch_add(1, Ordering::Relaxed);
+                Err(RunnableRunErr::TimedOut(timeout))
+            } else {
+                // The worker finished just after the timeout, so its result is on its way.
+                receiver.recv().unwrap_or_else(|_| {
+                    Err(RunnableRunErr::Panicked(
+                        "Worker thread exited without a result.".to_string(),
+                    ))
+                })
+            }
+        }
+        Err(RecvTimeoutError::Disconnected) => Err(RunnableRunErr::Panicked(
+            "Worker thread exited without a result.".to_string(),
+        )),
+    }
+}
+
+fn run_part<I>(
+    part: PartFn<I>,
+    input: Arc<I>,
     solution: Option<String>,
+    options: &RunnableOptions,
 ) -> RunnableRun
 where
-    T: ToString,
-    F: Fn(&str) -> T,
+    I: ?Sized + Send + Sync + 'static,
 {
-    let Runnable::Implemented(runnable) = runnable else {
-        return Err("Not implemented.".to_string());
+    let ((result, duration), memory) = {
+        let part = part.clone();
+        let input = input.clone();
+        run_on_worker(
+            move || {
+                memory::track(|| {
+                    let start = Instant::now();
+                    let result = part(&input);
+                    (result, start.elapsed())
+                })
+            },
+            options,
+        )?
     };
+    let result = result.map_err(RunnableRunErr::ReturnedError)?;
 
-    let start = Instant::now();
-    let result = ToString::to_string(&runnable(input));
-    let duration = start.elapsed();
+    // The timeout only applies to the first run, the benchmark is limited by its budget instead.
+    let stats = match options.bench.clone() {
+        Some(bench) => {
+            let options = RunnableOptions {
+                timeout: None,
+                ..options.clone()
+            };
+            let samples = run_on_worker(
+                move || {
+                    let start = Instant::now();
+                    let mut samples = Vec::new();
+                    while samples.is_empty()
+                        || (samples.len() < bench.iterations && start.elapsed() < bench.budget)
+                    {
+                        let start = Instant::now();
+                        let _ = hint::black_box(part(hint::black_box(&input)));
+                        samples.push(start.elapsed());
+                    }
+                    samples
+                },
+                &options,
+            )?;
+            Some(DurationStats::from_samples(&samples))
+        }
+        None => None,
+    };
 
     Ok(RunnableRunOk {
         result,
         solution,
-        duration,
+        duration: stats.as_ref().map_or(duration, |stats| stats.median),
+        stats,
+        parse_duration: None,
+        baseline: None,
+        memory,
     })
 }
 
+/// Parse a duration such as `500ms`, `10s`, `2m` or `1.5` (seconds).
+pub fn parse_duration(value: &str) -> Result<Duration, String> {
+    let (number, unit) = match value.find(char::is_alphabetic) {
+        Some(idx) => value.split_at(idx),
+        None => (value, "s"),
+    };
+    let number: f64 = number
+        .parse()
+        .map_err(|_| format!("Invalid duration '{value}'."))?;
+    let seconds = match unit {
+        "ns" => number / 1_000_000_000.0,
+        "us" | "µs" => number / 1_000_000.0,
+        "ms" => number / 1000.0,
+        "s" => number,
+        "m" => number * 60.0,
+        _ => return Err(format!("Invalid duration unit '{unit}' in '{value}'.")),
+    };
+    Duration::try_from_secs_f64(seconds).map_err(|_| format!("Invalid duration '{value}'."))
+}
+
+/// Parse a percentage (with or without `%`) into a fraction.
+pub fn parse_percentage(value: &str) -> Result<f64, String> {
+    let percentage: f64 = value
+        .trim_end_matches('%')
+        .parse()
+        .map_err(|_| format!("Invalid percentage '{value}'."))?;
+    Ok(percentage / 100.0)
+}
+
 #[allow(clippy::must_use_candidate)]
 pub fn get_input_path(name: &str) -> String {
     format!("inputs/{name}.txt")
 }
 
+fn is_solution_label(label: &str) -> bool {
+    label
+        .rsplit('.')
+        .next()
+        .and_then(|last| last.strip_prefix("solution"))
+        .is_some_and(|part| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Get the paths of all inputs of the given day, along with a label for each input other than the main one.
+///
+/// Besides the main input this includes any additional inputs (such as the examples from the puzzle text) stored as `inputs/dayXX.<label>.txt` or `inputs/dayXX/<label>.txt`.
+pub fn get_input_paths(name: &str) -> Vec<(Option<String>, String)> {
+    find_inputs("inputs", name)
+}
+
+fn find_inputs(root: &str, name: &str) -> Vec<(Option<String>, String)> {
+    let mut extra = Vec::new();
+    for (dir, prefix) in [
+        (root.to_string(), format!("{name}.")),
+        (format!("{root}/{name}"), String::new()),
+    ] {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let filename = entry.file_name().to_string_lossy().to_string();
+            let Some(label) = filename
+                .strip_prefix(&prefix)
+                .and_then(|filename| filename.strip_suffix(".txt"))
+            else {
+                continue;
+            };
+            if !label.is_empty() && !is_solution_label(label) {
+                extra.push((Some(label.to_string()), format!("{dir}/{filename}")));
+            }
+        }
+    }
+    extra.sort();
+
+    let mut paths = vec![(None, format!("{root}/{name}.txt"))];
+    paths.extend(extra);
+    paths
+}
+
 #[allow(clippy::must_use_candidate)]
-pub fn get_output_path(input_path: &str, part: i8) -> String {
+pub fn get_output_path(input_path: &str, part: usize) -> String {
     if input_path.contains('.') {
         let [tail, head]: [&str; 2] = input_path
             .rsplitn(2, '.')
@@ -148,64 +946,1197 @@ pub fn get_output_path(input_path: &str, part: i8) -> String {
     }
 }
 
-pub fn run_day<T1, F1, T2, F2>(
-    filename: &String,
+/// The source of the input for a run.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Input {
+    /// A file on disk. This is the only kind of input that can have expected solutions.
+    File(String),
+    /// Everything that is written to stdin.
+    Stdin,
+    /// An input given directly on the command line.
+    Text(String),
+}
+impl Input {
+    pub fn read(&self) -> Result<String, String> {
+        match self {
+            Input::File(filename) => fs::read_to_string(filename)
+                .map_err(|err| format!("Unable to read input file '{filename}': {err}.")),
+            Input::Stdin => io::read_to_string(io::stdin())
+                .map_err(|err| format!("Unable to read input from stdin: {err}.")),
+            Input::Text(text) => Ok(text.clone()),
+        }
+    }
+
+    /// The path of the file backing this input, if any.
+    pub fn path(&self) -> Option<&str> {
+        match self {
+            Input::File(filename) => Some(filename),
+            Input::Stdin | Input::Text(_) => None,
+        }
+    }
+
+    /// Read the answers file of this input, if it is a file.
+    fn read_answers(&self) -> Result<Option<InputAnswers>, String> {
+        self.path().map(InputAnswers::read).transpose()
+    }
+}
+impl Display for Input {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Input::File(filename) => write!(f, "{filename}"),
+            Input::Stdin => write!(f, "stdin"),
+            Input::Text(_) => write!(f, "inline text"),
+        }
+    }
+}
+
+/// The answers file of an input file, which is read once for each run of the input.
+struct InputAnswers {
+    filename: String,
+    answers: Answers,
+    /// The key of the input in the answers file.
+    key: String,
+}
+impl InputAnswers {
+    fn read(filename: &str) -> Result<Self, String> {
+        let (answers, key) = read_answers(filename)?;
+        Ok(Self {
+            filename: filename.to_string(),
+            answers,
+            key,
+        })
+    }
+
+    /// Get the expected solution for the given part.
+    ///
+    /// This is looked up in the answers file first, falling back to a separate solution file (see [`get_output_path`]).
+    fn solution(&self, part: usize) -> Option<String> {
+        self.answers
+            .get(&self.key, part)
+            .cloned()
+            .or_else(|| fs::read_to_string(get_output_path(&self.filename, part)).ok())
+    }
+
+    /// Get the options to run the input with, which includes the parameters from the answers file.
+    fn options(answers: Option<&Self>, options: &RunnableOptions) -> RunnableOptions {
+        let mut params = answers.map_or_else(BTreeMap::new, |answers| {
+            answers.answers.get_params(&answers.key)
+        });
+        params.extend(options.params.clone());
+        RunnableOptions {
+            params,
+            ..options.clone()
+        }
+    }
+}
+
+/// Read the answers file for the given input, returning it along with the key of the input in it.
+fn read_answers(filename: &str) -> Result<(Answers, String), String> {
+    let (answers_path, input) = get_answers_path(filename);
+    let answers = if Path::new(&answers_path).exists() {
+        Answers::load(&answers_path)?
+    } else {
+        Answers::default()
+    };
+    Ok((answers, input))
+}
+
+fn confirm(question: &str) -> bool {
+    if !io::stdin().is_terminal() {
+        return false;
+    }
+    eprint!("{question} [y/N] ");
+    let _ = io::stderr().flush();
+    let mut answer = String::new();
+    if io::stdin().read_line(&mut answer).is_err() {
+        return false;
+    }
+    matches!(answer.trim().to_lowercase().as_str(), "y" | "yes")
+}
+
+/// Save the results of the given runs in the answers file for the given input, so they are used as the expected solution from now on.
+///
+/// Existing solutions are kept as-is, unless the user confirms they should be overwritten. This is never done when not running interactively.
+pub fn save_solutions(filename: &str, runs: &[(usize, &RunnableRun)]) -> Result<(), String> {
+    save_solutions_confirm(filename, runs, confirm)
+}
+
+fn save_solutions_confirm(
+    filename: &str,
+    runs: &[(usize, &RunnableRun)],
+    confirm: impl Fn(&str) -> bool,
+) -> Result<(), String> {
+    let (answers_path, input) = get_answers_path(filename);
+
+    let mut changed = Vec::new();
+    for (part, run) in runs {
+        let Ok(run) = run else {
+            continue;
+        };
+        match &run.solution {
+            Some(solution) if diff::matches(&run.result.as_text(), solution) => continue,
+            Some(solution) => {
+                let question = format!(
+                    "The result of part {part} of {filename} ({}) differs from the expected solution ({solution}). Overwrite it?",
+                    run.result,
+                );
+                if !confirm(&question) {
+                    eprintln!(
+                        "{}",
+                        Red.paint_err(format!(
+                            "Not overwriting the solution of part {part} of {filename}."
+                        ))
+                    );
+                    continue;
+                }
+            }
+            None => {}
+        }
+        changed.push((*part, run.result.to_string()));
+        eprintln!(
+            "Saved the result of part {part} of {filename} to {}.",
+            Cyan.paint_err(&answers_path)
+        );
+    }
+
+    if !changed.is_empty() {
+        save_answers(&answers_path, &input, &changed)?;
+    }
+    Ok(())
+}
+
+/// A part with its answer converted to a string, so that parts with different types of answers can be stored together.
+pub type PartFn<I> = Arc<dyn Fn(&I) -> Result<Answer, String> + Send + Sync>;
+
+/// Convert a part that takes the raw input to a [`PartFn`].
+pub fn part_fn<T, F, M>(part: F) -> PartFn<str>
+where
+    T: PartOutput<M>,
+    F: Fn(&str) -> T + Send + Sync + 'static,
+{
+    Arc::new(move |input: &str| part(input).into_answer())
+}
+
+/// Convert a part that takes (something borrowed from) the parsed input to a [`PartFn`].
+pub fn parsed_part_fn<P, Q, T, M>(part: fn(&Q) -> T) -> PartFn<P>
+where
+    P: Borrow<Q> + 'static,
+    Q: ?Sized + 'static,
+    T: PartOutput<M> + 'static,
+{
+    Arc::new(move |parsed: &P| part(parsed.borrow()).into_answer())
+}
+
+/// The implementations of a single part of a day.
+pub struct Part<I: ?Sized> {
+    pub number: usize,
+    /// The reference implementation, or `None` if the part has not been implemented.
+    pub implementation: Option<PartFn<I>>,
+    /// Named alternative implementations, which are run as well and checked against the reference implementation.
+    pub alternatives: Vec<(&'static str, PartFn<I>)>,
+}
+impl<I: ?Sized> Part<I> {
+    pub fn new(number: usize, implementation: Option<PartFn<I>>) -> Self {
+        Self {
+            number,
+            implementation,
+            alternatives: Vec::new(),
+        }
+    }
+}
+
+/// Run all implementations of a part. The alternatives are run even if the reference implementation fails, see [`PartRun::alternative_solution`].
+fn run_implementations<I>(
+    part: &Part<I>,
+    input: &Arc<I>,
+    solution: Option<String>,
+    options: &RunnableOptions,
+) -> PartRun
+where
+    I: ?Sized + Send + Sync + 'static,
+{
+    let run = |implementation: &PartFn<I>, solution| {
+        run_part(implementation.clone(), input.clone(), solution, options)
+    };
+    let mut part_run = PartRun {
+        part: part.number,
+        solution: solution.clone(),
+        run: part
+            .implementation
+            .as_ref()
+            .map_or(Err(RunnableRunErr::Missing), |implementation| {
+                run(implementation, solution)
+            }),
+        alternatives: Vec::new(),
+    };
+    let expected = part_run.alternative_solution();
+    part_run.alternatives = part
+        .alternatives
+        .iter()
+        .map(|(name, implementation)| ((*name).to_string(), run(implementation, expected.clone())))
+        .collect();
+    part_run
+}
+
+/// Run the given parts of a day.
+pub fn run_parts(
+    input: &Input,
+    parts: &[Part<str>],
+    options: &RunnableOptions,
+) -> Result<Vec<PartRun>, String> {
+    let contents: Arc<str> = input.read()?.into();
+    let answers = input.read_answers()?;
+    let options = &InputAnswers::options(answers.as_ref(), options);
+    Ok(parts
+        .iter()
+        .map(|part| {
+            let solution = answers
+                .as_ref()
+                .and_then(|answers| answers.solution(part.number));
+            run_implementations(part, &contents, solution, options)
+        })
+        .collect())
+}
+
+/// Like [`run_parts`], but for days where the input is parsed once, with the parsed value then being shared between the parts.
+///
+/// The time spent parsing is reported separately from the time spent in the parts themselves.
+pub fn run_parts_parsed<P>(
+    input: &Input,
+    parse: fn(&str) -> P,
+    parts: &[Part<P>],
+    options: &RunnableOptions,
+) -> Result<Vec<PartRun>, String>
+where
+    P: Send + Sync + 'static,
+{
+    run_parts_with_parser(input, move |contents| Ok(parse(contents)), parts, options)
+}
+
+/// Like [`run_parts_parsed`], but for parsers that return a [`Result`]. If parsing fails, all parts are reported as having returned the error.
+pub fn run_parts_try_parsed<P, E>(
+    input: &Input,
+    parse: fn(&str) -> Result<P, E>,
+    parts: &[Part<P>],
+    options: &RunnableOptions,
+) -> Result<Vec<PartRun>, String>
+where
+    P: Send + Sync + 'static,
+    E: Display + 'static,
+{
+    run_parts_with_parser(
+        input,
+        move |contents| parse(contents).map_err(|err| err.to_string()),
+        parts,
+        options,
+    )
+}
+
+fn run_parts_with_parser<P>(
+    input: &Input,
+    parse: impl FnOnce(&str) -> Result<P, String> + Send + 'static,
+    parts: &[Part<P>],
+    options: &RunnableOptions,
+) -> Result<Vec<PartRun>, String>
+where
+    P: Send + Sync + 'static,
+{
+    let contents = input.read()?;
+    let answers = input.read_answers()?;
+    let solutions: Vec<_> = parts
+        .iter()
+        .map(|part| {
+            answers
+                .as_ref()
+                .and_then(|answers| answers.solution(part.number))
+        })
+        .collect();
+    let failed = |err: &RunnableRunErr| {
+        parts
+            .iter()
+            .zip(&solutions)
+            .map(|(part, solution)| PartRun {
+                part: part.number,
+                solution: solution.clone(),
+                run: Err(err.clone()),
+                alternatives: Vec::new(),
+            })
+            .collect()
+    };
+    if parts.iter().all(|part| part.implementation.is_none()) {
+        return Ok(failed(&RunnableRunErr::Missing));
+    }
+    let options = &InputAnswers::options(answers.as_ref(), options);
+
+    let parsed = run_on_worker(
+        move || {
+            let start = Instant::now();
+            let parsed = parse(&contents);
+            (parsed, start.elapsed())
+        },
+        options,
+    );
+    let (parsed, parse_duration) = match parsed {
+        Ok((Ok(parsed), parse_duration)) => (Arc::new(parsed), parse_duration),
+        Ok((Err(err), _)) => return Ok(failed(&RunnableRunErr::ReturnedError(err))),
+        Err(err) => return Ok(failed(&err)),
+    };
+
+    Ok(parts
+        .iter()
+        .zip(solutions)
+        .map(|(part, solution)| {
+            let mut run = run_implementations(part, &parsed, solution, options);
+            if let Ok(run) = &mut run.run {
+                run.parse_duration = Some(parse_duration);
+            }
+            run
+        })
+        .collect())
+}
+
+/// Get the runs of the two parts of a day from the result of [`run_parts`].
+fn into_pair(runs: Vec<PartRun>) -> Result<(RunnableRun, RunnableRun), String> {
+    match <[PartRun; 2]>::try_from(runs) {
+        Ok([part1, part2]) => Ok((part1.run, part2.run)),
+        Err(runs) => Err(format!(
+            "Expected the runs of 2 parts, but got {}.",
+            runs.len()
+        )),
+    }
+}
+
+pub fn run_day<T1, F1, T2, F2, M1, M2>(
+    input: &Input,
     part1: &Runnable<T1, F1>,
     part2: &Runnable<T2, F2>,
+    options: &RunnableOptions,
 ) -> Result<(RunnableRun, RunnableRun), String>
 where
-    T1: ToString,
-    F1: Fn(&str) -> T1,
-    T2: ToString,
-    F2: Fn(&str) -> T2,
-{
-    match fs::read_to_string(filename) {
-        Ok(input) => Ok((
-            run_runnable(
-                part1,
-                &input,
-                fs::read_to_string(get_output_path(filename, 1)).ok(),
-            ),
-            run_runnable(
-                part2,
-                &input,
-                fs::read_to_string(get_output_path(filename, 2)).ok(),
-            ),
-        )),
-        Err(err) => Err(format!("Unable to read input file '{filename}': {err}.")),
-    }
+    T1: PartOutput<M1> + 'static,
+    F1: Fn(&str) -> T1 + Clone + Send + Sync + 'static,
+    T2: PartOutput<M2> + 'static,
+    F2: Fn(&str) -> T2 + Clone + Send + Sync + 'static,
+{
+    run_parts(
+        input,
+        &[
+            Part::new(1, part1.to_part_fn()),
+            Part::new(2, part2.to_part_fn()),
+        ],
+        options,
+    )
+    .and_then(into_pair)
 }
 
-pub fn run<T1, F1, T2, F2>(part1: impl Into<Runnable<T1, F1>>, part2: impl Into<Runnable<T2, F2>>)
+/// Run a day where the input is parsed once, with the parsed value then being shared between the parts. See [`run_parts_parsed`].
+///
+/// The parts may take anything the parsed value can be borrowed as (e.g. a slice when parsing produces a `Vec`).
+pub fn run_day_parsed<P, Q1, Q2, T1, T2, M1, M2>(
+    input: &Input,
+    parse: fn(&str) -> P,
+    part1: Option<fn(&Q1) -> T1>,
+    part2: Option<fn(&Q2) -> T2>,
+    options: &RunnableOptions,
+) -> Result<(RunnableRun, RunnableRun), String>
 where
-    T1: ToString,
-    F1: Fn(&str) -> T1,
-    T2: ToString,
-    F2: Fn(&str) -> T2,
+    P: Borrow<Q1> + Borrow<Q2> + Send + Sync + 'static,
+    Q1: ?Sized + 'static,
+    Q2: ?Sized + 'static,
+    T1: PartOutput<M1> + 'static,
+    T2: PartOutput<M2> + 'static,
 {
-    let args: Vec<String> = env::args().collect();
+    run_parts_parsed(
+        input,
+        parse,
+        &[
+            Part::new(1, part1.map(parsed_part_fn)),
+            Part::new(2, part2.map(parsed_part_fn)),
+        ],
+        options,
+    )
+    .and_then(into_pair)
+}
+
+/// Like [`run_day_parsed`], but for parsers that return a [`Result`]. See [`run_parts_try_parsed`].
+pub fn run_day_try_parsed<P, E, Q1, Q2, T1, T2, M1, M2>(
+    input: &Input,
+    parse: fn(&str) -> Result<P, E>,
+    part1: Option<fn(&Q1) -> T1>,
+    part2: Option<fn(&Q2) -> T2>,
+    options: &RunnableOptions,
+) -> Result<(RunnableRun, RunnableRun), String>
+where
+    P: Borrow<Q1> + Borrow<Q2> + Send + Sync + 'static,
+    E: Display + 'static,
+    Q1: ?Sized + 'static,
+    Q2: ?Sized + 'static,
+    T1: PartOutput<M1> + 'static,
+    T2: PartOutput<M2> + 'static,
+{
+    run_parts_try_parsed(
+        input,
+        parse,
+        &[
+            Part::new(1, part1.map(parsed_part_fn)),
+            Part::new(2, part2.map(parsed_part_fn)),
+        ],
+        options,
+    )
+    .and_then(into_pair)
+}
 
-    let name = args[0]
+const WATCH_INTERVAL_DEFAULT: Duration = Duration::from_millis(500);
+
+/// Print the given error and exit with a non-zero exit code.
+fn exit_with_error(err: &str) -> ! {
+    eprintln!("{}", Red.paint_err(err));
+    process::exit(1);
+}
+
+/// The arguments of a single day binary.
+struct RunArgs {
+    options: RunnableOptions,
+    thresholds: Thresholds,
+    inputs: Vec<Input>,
+    watch_interval: Option<Duration>,
+}
+impl RunArgs {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut options = RunnableOptions::default();
+        let mut thresholds = ThresholdOptions::from_env();
+        let mut inputs: Vec<Input> = Vec::new();
+        let mut watch_interval = None;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-" => inputs.push(Input::Stdin),
+                "--watch" => {
+                    watch_interval.get_or_insert(WATCH_INTERVAL_DEFAULT);
+                }
+                "--watch-interval" => {
+                    let value = args.next().ok_or("Missing value for --watch-interval.")?;
+                    watch_interval = Some(parse_duration(&value)?);
+                }
+                "--input-text" => inputs.push(Input::Text(
+                    args.next().ok_or("Missing value for --input-text.")?,
+                )),
+                _ => {
+                    if !options.parse_arg(&arg, &mut args)?
+                        && !thresholds.parse_arg(&arg, &mut args)?
+                    {
+                        inputs.push(Input::File(arg));
+                    }
+                }
+            }
+        }
+        Ok(Self {
+            options,
+            thresholds: thresholds.resolve(Thresholds::ABSOLUTE_DEFAULT)?,
+            inputs,
+            watch_interval,
+        })
+    }
+}
+
+fn run_inputs(run_input: impl Fn(&Input, &RunnableOptions) -> Result<Vec<PartRun>, String>) {
+    let mut args = env::args();
+
+    let name = args.next().expect("Unable to determine binary name.");
+    let name = name
         .split('/')
-        .last()
+        .next_back()
         .expect("Unable to determine binary name.");
 
-    let filenames: Vec<String> = if args.len() > 1 {
-        args.iter().skip(1).cloned().collect()
-    } else {
-        vec![get_input_path(name)]
-    };
+    colour::init();
+    let RunArgs {
+        options,
+        thresholds,
+        mut inputs,
+        watch_interval,
+    } = RunArgs::parse(args).unwrap_or_else(|err| exit_with_error(&err));
+    if inputs.is_empty() {
+        inputs = get_input_paths(name)
+            .into_iter()
+            .map(|(_, path)| Input::File(path))
+            .collect();
+    }
 
-    let part1 = part1.into();
-    let part2 = part2.into();
-    for filename in &filenames {
+    let mut results = Vec::new();
+    for input in &inputs {
         println!(
             "Running {} using input {}...",
             Cyan.paint(name),
-            Cyan.paint(filename)
+            Cyan.paint(input.to_string())
+        );
+        let runs = run_input(input, &options).unwrap_or_else(|err| exit_with_error(&err));
+        let durations: Vec<Duration> = runs
+            .iter()
+            .filter_map(|run| run.run.as_ref().ok().map(|run| run.duration))
+            .collect();
+        let average = if durations.is_empty() {
+            Duration::ZERO
+        } else {
+            durations.iter().sum::<Duration>() / durations.len() as u32
+        };
+        let thresholds = thresholds.for_average(average, REGRESSION_DEFAULT);
+        for run in &runs {
+            print_runnable_run(
+                format!("Part {}", run.part),
+                run.run.clone(),
+                &run.alternatives,
+                &thresholds,
+                true,
+            );
+        }
+        if options.save {
+            match input.path() {
+                Some(filename) => {
+                    let runs: Vec<_> = runs.iter().map(|run| (run.part, &run.run)).collect();
+                    save_solutions(filename, &runs).unwrap_or_else(|err| exit_with_error(&err));
+                }
+                None => eprintln!(
+                    "{}",
+                    Red.paint_err(format!(
+                        "Not saving the results for {input} as it is not a file."
+                    ))
+                ),
+            }
+        }
+        results.push(runs);
+    }
+    warn_if_timings_skewed();
+    warn_unused_params();
+
+    if let Some(interval) = watch_interval {
+        watch::watch(
+            &inputs,
+            &watch::get_source_path(name),
+            interval,
+            results,
+            |input| run_input(input, &options),
+        );
+    }
+}
+
+/// Run the day binary this is called from, using the parts registered with `#[aoc::solution(...)]`.
+///
+/// Unlike [`run`] this also runs bonus parts (and alternative implementations), as all registered parts are run.
+pub fn run_registered() {
+    let solution = match registered_solutions(&[]) {
+        Ok(solutions) if solutions.len() == 1 => solutions.into_iter().next().unwrap(),
+        Ok(solutions) => exit_with_error(&format!(
+            "Expected the parts of a single day to be registered, but found {} days.",
+            solutions.len()
+        )),
+        Err(err) => exit_with_error(&err),
+    };
+    let parts: Vec<usize> = solution.parts().iter().map(|part| part.number).collect();
+    run_inputs(|input, options| solution.run(input, &parts, options));
+}
+
+pub fn run<T1, F1, T2, F2, M1, M2>(
+    part1: impl Into<Runnable<T1, F1>>,
+    part2: impl Into<Runnable<T2, F2>>,
+) where
+    T1: PartOutput<M1> + 'static,
+    F1: Fn(&str) -> T1 + Clone + Send + Sync + 'static,
+    T2: PartOutput<M2> + 'static,
+    F2: Fn(&str) -> T2 + Clone + Send + Sync + 'static,
+{
+    let implementations = [
+        Part::new(1, part1.into().to_part_fn()),
+        Part::new(2, part2.into().to_part_fn()),
+    ];
+    run_inputs(|input, options| run_parts(input, &implementations, options));
+}
+
+/// Like [`run`], but for days that parse the input once and share the result between the parts. See [`run_day_parsed`].
+pub fn run_parsed<P, Q1, Q2, T1, T2, M1, M2>(
+    parse: fn(&str) -> P,
+    part1: fn(&Q1) -> T1,
+    part2: fn(&Q2) -> T2,
+) where
+    P: Borrow<Q1> + Borrow<Q2> + Send + Sync + 'static,
+    Q1: ?Sized + 'static,
+    Q2: ?Sized + 'static,
+    T1: PartOutput<M1> + 'static,
+    T2: PartOutput<M2> + 'static,
+{
+    let implementations = [
+        Part::new(1, Some(parsed_part_fn(part1))),
+        Part::new(2, Some(parsed_part_fn(part2))),
+    ];
+    run_inputs(|input, options| run_parts_parsed(input, parse, &implementations, options));
+}
+
+/// Like [`run_parsed`], but for parsers that return a [`Result`]. See [`run_day_try_parsed`].
+pub fn run_try_parsed<P, E, Q1, Q2, T1, T2, M1, M2>(
+    parse: fn(&str) -> Result<P, E>,
+    part1: fn(&Q1) -> T1,
+    part2: fn(&Q2) -> T2,
+) where
+    P: Borrow<Q1> + Borrow<Q2> + Send + Sync + 'static,
+    E: Display + 'static,
+    Q1: ?Sized + 'static,
+    Q2: ?Sized + 'static,
+    T1: PartOutput<M1> + 'static,
+    T2: PartOutput<M2> + 'static,
+{
+    let implementations = [
+        Part::new(1, Some(parsed_part_fn(part1))),
+        Part::new(2, Some(parsed_part_fn(part2))),
+    ];
+    run_inputs(|input, options| run_parts_try_parsed(input, parse, &implementations, options));
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::answers::ANSWERS_FILENAME;
+
+    fn run_runnable<T, F, M>(
+        runnable: &Runnable<T, F>,
+        input: &Arc<str>,
+        solution: Option<String>,
+        options: &RunnableOptions,
+    ) -> RunnableRun
+    where
+        T: PartOutput<M>,
+        F: Fn(&str) -> T + Clone + Send + Sync + 'static,
+    {
+        run_implementations(
+            &Part::new(1, runnable.to_part_fn()),
+            input,
+            solution,
+            options,
+        )
+        .run
+    }
+
+    #[test]
+    fn run_runnable_ok() {
+        let run = run_runnable(
+            &Runnable::from(str::len),
+            &"foo".into(),
+            Some("3".to_string()),
+            &RunnableOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(run.result, "3");
+        assert_eq!(run.solution, Some("3".to_string()));
+    }
+
+    #[test]
+    fn baseline_change() {
+        let mut run = run_runnable(
+            &Runnable::from(str::len),
+            &"foo".into(),
+            None,
+            &RunnableOptions::default(),
+        )
+        .unwrap();
+        run.duration = Duration::from_millis(3);
+        assert_eq!(run.baseline_change(), None);
+        run.baseline = Some(Duration::from_millis(2));
+        assert_eq!(run.baseline_change(), Some(0.5));
+        run.baseline = Some(Duration::ZERO);
+        assert_eq!(run.baseline_change(), None);
+    }
+
+    #[test]
+    fn run_runnable_missing() {
+        let run = run_runnable(
+            &Runnable::from(()),
+            &"foo".into(),
+            None,
+            &RunnableOptions::default(),
+        );
+        assert_eq!(run.err(), Some(RunnableRunErr::Missing));
+    }
+
+    #[test]
+    fn run_runnable_result() {
+        let part = |input: &str| input.parse::<u8>().map(|n| n * 2);
+        let run = run_runnable(
+            &Runnable::from(part),
+            &"21".into(),
+            None,
+            &RunnableOptions::default(),
+        );
+        assert_eq!(run.unwrap().result, "42");
+        let run = run_runnable(
+            &Runnable::from(part),
+            &"foo".into(),
+            None,
+            &RunnableOptions::default(),
+        );
+        assert_eq!(
+            run.err(),
+            Some(RunnableRunErr::ReturnedError(
+                "invalid digit found in string".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn run_runnable_panic() {
+        let runnable: Runnable<usize, _> = Runnable::from(|input: &str| input.parse().unwrap());
+        let Err(RunnableRunErr::Panicked(message)) =
+            run_runnable(&runnable, &"foo".into(), None, &RunnableOptions::default())
+        else {
+            panic!("Expected run to panic.");
+        };
+        assert!(message.starts_with("Panicked at src/runner.rs:"));
+        assert!(message.ends_with(
+            "called `Result::unwrap()` on an `Err` value: ParseIntError { kind: InvalidDigit }"
+        ));
+    }
+
+    #[test]
+    fn run_runnable_timeout() {
+        let runnable: Runnable<usize, _> = Runnable::from(|_: &str| loop {
+            check_cancelled();
+        });
+        let options = RunnableOptions {
+            timeout: Some(Duration::from_millis(10)),
+            ..RunnableOptions::default()
+        };
+        let run = run_runnable(&runnable, &"foo".into(), None, &options);
+        assert_eq!(
+            run.err(),
+            Some(RunnableRunErr::TimedOut(Duration::from_millis(10)))
+        );
+    }
+
+    #[test]
+    fn run_runnable_without_timeout_runs_inline() {
+        let current = thread::current().id();
+        let runnable: Runnable<usize, _> =
+            Runnable::from(move |_: &str| usize::from(thread::current().id() == current));
+        let run = run_runnable(&runnable, &"foo".into(), None, &RunnableOptions::default());
+        assert_eq!(run.unwrap().result, "1");
+    }
+
+    #[test]
+    fn run_runnable_bench() {
+        let options = RunnableOptions {
+            bench: Some(BenchOptions {
+                iterations: 5,
+                budget: Duration::from_secs(1),
+            }),
+            ..RunnableOptions::default()
+        };
+        let run = run_runnable(&Runnable::from(str::len), &"foo".into(), None, &options).unwrap();
+        assert_eq!(run.result, "3");
+        let stats = run.stats.unwrap();
+        assert_eq!(stats.samples, 5);
+        assert_eq!(run.duration, stats.median);
+    }
+
+    #[test]
+    fn run_runnable_bench_timeout_applies_to_first_run() {
+        let runnable: Runnable<usize, _> = Runnable::from(|input: &str| {
+            thread::sleep(Duration::from_millis(5));
+            input.len()
+        });
+        let options = RunnableOptions {
+            timeout: Some(Duration::from_millis(200)),
+            bench: Some(BenchOptions {
+                iterations: 50,
+                budget: Duration::from_secs(1),
+            }),
+            ..RunnableOptions::default()
+        };
+        let run = run_runnable(&runnable, &"foo".into(), None, &options).unwrap();
+        assert_eq!(run.result, "3");
+        assert_eq!(run.stats.unwrap().samples, 50);
+    }
+
+    #[test]
+    fn run_day_parsed_shares_parse() {
+        fn parse(input: &str) -> Vec<usize> {
+            input.split(',').map(|n| n.parse().unwrap()).collect()
+        }
+        fn sum(numbers: &[usize]) -> usize {
+            numbers.iter().sum()
+        }
+
+        let (run1, run2) = run_day_parsed(
+            &Input::Text("1,2,3".to_string()),
+            parse,
+            Some(sum as fn(&_) -> _),
+            None::<fn(&Vec<usize>) -> usize>,
+            &RunnableOptions::default(),
+        )
+        .unwrap();
+
+        let run1 = run1.unwrap();
+        assert_eq!(run1.result, "6");
+        assert!(run1.parse_duration.is_some());
+        assert_eq!(run2.err(), Some(RunnableRunErr::Missing));
+    }
+
+    #[test]
+    fn run_day_try_parsed_rejects_input() {
+        fn parse(input: &str) -> Result<Vec<usize>, String> {
+            input
+                .split(',')
+                .map(|n| n.parse().map_err(|_| format!("Invalid number '{n}'.")))
+                .collect()
+        }
+        fn sum(numbers: &[usize]) -> usize {
+            numbers.iter().sum()
+        }
+
+        let (run1, run2) = run_day_try_parsed(
+            &Input::Text("1,x".to_string()),
+            parse,
+            Some(sum as fn(&_) -> _),
+            Some(sum as fn(&_) -> _),
+            &RunnableOptions::default(),
+        )
+        .unwrap();
+
+        let errored = RunnableRunErr::ReturnedError("Invalid number 'x'.".to_string());
+        assert_eq!(run1.err(), Some(errored.clone()));
+        assert_eq!(run2.err(), Some(errored));
+    }
+
+    #[test]
+    fn part_fn_display() {
+        struct Position(i32, i32);
+        impl Display for Position {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{},{}", self.0, self.1)
+            }
+        }
+
+        let part = part_fn(|input: &str| Position(input.len().try_into().unwrap(), -1));
+        assert_eq!(part("foo"), Ok(Answer::Text("3,-1".to_string())));
+        let part = part_fn(|input: &str| input.parse::<u8>());
+        assert_eq!(part("12"), Ok(Answer::Integer(12)));
+        assert_eq!(part("x"), Err("invalid digit found in string".to_string()));
+    }
+
+    #[test]
+    fn run_parts_bonus() {
+        let runs = run_parts(
+            &Input::Text("foo".to_string()),
+            &[
+                Part::new(1, Some(part_fn(str::len))),
+                Part::new(2, None),
+                Part::new(3, Some(part_fn(str::to_uppercase))),
+            ],
+            &RunnableOptions::default(),
+        )
+        .unwrap();
+        let results: Vec<(usize, Option<Answer>)> = runs
+            .into_iter()
+            .map(|run| (run.part, run.run.ok().map(|run| run.result)))
+            .collect();
+        assert_eq!(
+            results,
+            vec![
+                (1, Some(Answer::Integer(3))),
+                (2, None),
+                (3, Some(Answer::Text("FOO".to_string())))
+            ]
+        );
+    }
+
+    #[test]
+    fn run_parts_alternatives() {
+        let runs = run_parts(
+            &Input::Text("foo".to_string()),
+            &[Part {
+                number: 1,
+                implementation: Some(part_fn(str::len)),
+                alternatives: vec![
+                    ("chars", part_fn(|input: &str| input.chars().count())),
+                    ("wrong", part_fn(|_: &str| 4)),
+                ],
+            }],
+            &RunnableOptions::default(),
+        )
+        .unwrap();
+        let run = &runs[0];
+        let results: Vec<(&str, Option<&Answer>)> = run
+            .alternatives
+            .iter()
+            .map(|(name, run)| (name.as_str(), run.as_ref().ok().map(|run| &run.result)))
+            .collect();
+        assert_eq!(
+            results,
+            vec![
+                ("chars", Some(&Answer::Integer(3))),
+                ("wrong", Some(&Answer::Integer(4)))
+            ]
+        );
+        assert!(!run.alternatives_agree());
+        assert_eq!(
+            get_problems(run.run.as_ref().unwrap(), &run.alternatives),
+            vec!["alternatives disagree".to_string()]
+        );
+    }
+
+    #[test]
+    fn problems() {
+        let mut run = run_runnable(
+            &Runnable::from(str::len),
+            &"foo".into(),
+            Some("3".to_string()),
+            &RunnableOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(get_problems(&run, &[]), Vec::<String>::new());
+        run.solution = Some("4\n".to_string());
+        assert_eq!(
+            get_problems(&run, &[]),
+            vec!["wrong, should be 4".to_string()]
+        );
+        run.solution = Some("4\n5\n".to_string());
+        assert_eq!(get_problems(&run, &[]), vec!["wrong".to_string()]);
+    }
+
+    #[test]
+    fn run_parts_alternatives_without_reference() {
+        let runs = run_parts(
+            &Input::Text("foo".to_string()),
+            &[Part {
+                number: 1,
+                implementation: Some(part_fn(|_: &str| Err::<usize, _>("Unsupported."))),
+                alternatives: vec![("len", part_fn(str::len))],
+            }],
+            &RunnableOptions::default(),
+        )
+        .unwrap();
+        let run = &runs[0];
+        assert_eq!(
+            run.run.as_ref().err(),
+            Some(&RunnableRunErr::ReturnedError("Unsupported.".to_string()))
+        );
+        let (name, alternative) = &run.alternatives[0];
+        assert_eq!(name, "len");
+        assert_eq!(alternative.as_ref().unwrap().result, Answer::Integer(3));
+        assert!(run.alternatives_agree());
+    }
+
+    #[test]
+    fn save_solutions_keeps_existing() {
+        let dir = env::temp_dir().join(format!("aoc-save-solutions-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let answers_path = dir.join(ANSWERS_FILENAME);
+        fs::write(&answers_path, "# Comment.\n[\"day01.txt\"]\n1 = 5\n").unwrap();
+
+        let run = |result: &str, solution: Option<&str>| {
+            Ok(RunnableRunOk {
+                result: Answer::from(result.to_string()),
+                solution: solution.map(str::to_string),
+                duration: Duration::ZERO,
+                stats: None,
+                parse_duration: None,
+                baseline: None,
+                memory: None,
+            })
+        };
+        let filename = dir.join("day01.txt");
+        save_solutions_confirm(
+            filename.to_str().unwrap(),
+            &[(1, &run("6", Some("5"))), (2, &run("7", None))],
+            |_| false,
+        )
+        .unwrap();
+
+        let contents = fs::read_to_string(&answers_path).unwrap();
+        fs::remove_dir_all(dir).unwrap();
+        assert_eq!(contents, "# Comment.\n[\"day01.txt\"]\n1 = 5\n2 = 7\n");
+    }
+
+    #[test]
+    fn find_inputs_includes_examples() {
+        let dir = env::temp_dir().join(format!("aoc-find-inputs-{}", process::id()));
+        let root = dir.to_str().unwrap();
+        fs::create_dir_all(dir.join("day15")).unwrap();
+        for file in [
+            "day15.txt",
+            "day15.solution1.txt",
+            "day15.example.txt",
+            "day15.example.solution2.txt",
+            "day15/small.txt",
+            "day15/small.solution1.txt",
+            "day15/answers.toml",
+            "day16.example.txt",
+        ] {
+            fs::write(dir.join(file), "").unwrap();
+        }
+
+        let inputs = find_inputs(root, "day15");
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            inputs,
+            vec![
+                (None, format!("{root}/day15.txt")),
+                (
+                    Some("example".to_string()),
+                    format!("{root}/day15.example.txt")
+                ),
+                (Some("small".to_string()), format!("{root}/day15/small.txt")),
+            ]
+        );
+    }
+
+    #[test]
+    fn run_runnable_param() {
+        let runnable = Runnable::from(|_: &str| param::<usize>("n", 1) * 2);
+        let run = run_runnable(&runnable, &"".into(), None, &RunnableOptions::default());
+        assert_eq!(run.unwrap().result, "2");
+
+        let options = RunnableOptions {
+            params: BTreeMap::from([("n".to_string(), "5".to_string())]),
+            ..RunnableOptions::default()
+        };
+        let run = run_runnable(&runnable, &"".into(), None, &options);
+        assert_eq!(run.unwrap().result, "10");
+    }
+
+    #[test]
+    fn run_runnable_unused_param() {
+        let runnable = Runnable::from(|_: &str| param::<usize>("unused_param_test_read", 1));
+        let options = RunnableOptions {
+            params: BTreeMap::from([
+                ("unused_param_test_read".to_string(), "5".to_string()),
+                ("unused_param_test_typo".to_string(), "5".to_string()),
+            ]),
+            ..RunnableOptions::default()
+        };
+        run_runnable(&runnable, &"".into(), None, &options).unwrap();
+        let unused = unused_params();
+        assert!(unused.contains(&"unused_param_test_typo".to_string()));
+        assert!(!unused.contains(&"unused_param_test_read".to_string()));
+    }
+
+    #[test]
+    fn duration_stats() {
+        let stats = DurationStats::from_samples(&[
+            Duration::from_millis(4),
+            Duration::from_millis(2),
+            Duration::from_millis(8),
+            Duration::from_millis(6),
+        ]);
+        assert_eq!(
+            stats,
+            DurationStats {
+                samples: 4,
+                min: Duration::from_millis(2),
+                median: Duration::from_millis(5),
+                mean: Duration::from_millis(5),
+                stddev: Duration::from_secs_f64(5f64.sqrt() / 1000.0),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_duration_valid() {
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("10s"), Ok(Duration::from_secs(10)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert_eq!(parse_duration("1.5"), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse_duration("20µs"), Ok(Duration::from_micros(20)));
+    }
+
+    #[test]
+    fn thresholds() {
+        let mut options = ThresholdOptions::default();
+        assert_eq!(
+            options.resolve(Thresholds::ABSOLUTE_DEFAULT),
+            Ok(Thresholds::ABSOLUTE_DEFAULT)
+        );
+
+        let mut args = ["relative", "50%"].into_iter().map(str::to_string);
+        assert_eq!(options.parse_arg("--thresholds", &mut args), Ok(true));
+        assert_eq!(options.parse_arg("--threshold-good", &mut args), Ok(true));
+        assert_eq!(options.parse_arg("--timeout", &mut args), Ok(false));
+        let thresholds = options.resolve(Thresholds::ABSOLUTE_DEFAULT).unwrap();
+        assert_eq!(
+            thresholds,
+            Thresholds::Relative {
+                good: 0.5,
+                acceptable: 2.0 / 3.0
+            }
+        );
+        let resolved = thresholds.for_average(Duration::from_millis(30), 0.1);
+        assert_eq!(resolved.good, Duration::from_millis(15));
+        assert_eq!(resolved.acceptable, Duration::from_millis(20));
+
+        options.mode = Some("absolute".to_string());
+        assert_eq!(
+            options.resolve(Thresholds::RELATIVE_DEFAULT),
+            Err("Invalid duration '50%'.".to_string())
+        );
+        options.mode = Some("fast".to_string());
+        assert_eq!(
+            options.resolve(Thresholds::RELATIVE_DEFAULT),
+            Err("Unknown thresholds mode 'fast'.".to_string())
+        );
+    }
+
+    #[test]
+    fn thresholds_implied_mode() {
+        let options = ThresholdOptions {
+            good: Some("50%".to_string()),
+            ..ThresholdOptions::default()
+        };
+        assert_eq!(
+            options.resolve(Thresholds::ABSOLUTE_DEFAULT),
+            Ok(Thresholds::Relative {
+                good: 0.5,
+                acceptable: 2.0 / 3.0
+            })
+        );
+        let options = ThresholdOptions {
+            acceptable: Some("100ms".to_string()),
+            ..ThresholdOptions::default()
+        };
+        assert_eq!(
+            options.resolve(Thresholds::RELATIVE_DEFAULT),
+            Ok(Thresholds::Absolute {
+                good: Duration::from_millis(1),
+                acceptable: Duration::from_millis(100)
+            })
+        );
+        let options = ThresholdOptions {
+            good: Some("0.5".to_string()),
+            ..ThresholdOptions::default()
+        };
+        assert_eq!(
+            options.resolve(Thresholds::ABSOLUTE_DEFAULT),
+            Ok(Thresholds::Absolute {
+                good: Duration::from_millis(500),
+                acceptable: Duration::from_secs(1)
+            })
+        );
+        let options = ThresholdOptions {
+            good: Some("1ms".to_string()),
+            acceptable: Some("50%".to_string()),
+            ..ThresholdOptions::default()
+        };
+        assert_eq!(
+            options.resolve(Thresholds::ABSOLUTE_DEFAULT),
+            Err("Thresholds cannot mix a percentage and a duration.".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_duration_invalid() {
+        assert_eq!(
+            parse_duration("ten"),
+            Err("Invalid duration 'ten'.".to_string())
+        );
+        assert_eq!(
+            parse_duration("10h"),
+            Err("Invalid duration unit 'h' in '10h'.".to_string())
         );
-        let (run1, run2) = run_day(filename, &part1, &part2).unwrap();
-        print_runnable_run("Part 1".to_string(), run1, &THRESHOLDS_DEFAULT, true);
-        print_runnable_run("Part 2".to_string(), run2, &THRESHOLDS_DEFAULT, true);
     }
 }
@@ -0,0 +1,390 @@
+use std::{any::Any, borrow::Cow, collections::BTreeMap, fmt::Display, sync::Arc};
+
+use crate::runner::{
+    run_parts, run_parts_try_parsed, Input, Part, PartFn, PartRun, RunnableOptions,
+};
+
+/// The answer of a part.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+}
+impl Answer {
+    /// Get the answer returned by a part. Answers of the integer types are integers, anything else is text.
+    pub fn of<T: Display + 'static>(value: T) -> Self {
+        let any: &dyn Any = &value;
+        macro_rules! integer {
+            ($($type:ty),*) => {
+                $(
+                    // Only a u128 can fail to fit, in which case it is kept as text.
+                    let number = any
+                        .downcast_ref::<$type>()
+                        .and_then(|number| i128::try_from(*number).ok());
+                    if let Some(number) = number {
+                        return Answer::Integer(number);
+                    }
+                )*
+            };
+        }
+        integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+        Answer::Text(value.to_string())
+    }
+
+    /// Get the answer as text, which is how it is compared to the expected solution.
+    pub fn as_text(&self) -> Cow<'_, str> {
+        match self {
+            Answer::Integer(value) => Cow::Owned(value.to_string()),
+            Answer::Text(value) => Cow::Borrowed(value),
+        }
+    }
+}
+impl From<String> for Answer {
+    /// Interpret a textual answer, such as an expected solution read from a file. Anything that is written exactly like an integer is an integer, everything else is text.
+    fn from(value: String) -> Self {
+        match value.parse::<i128>() {
+            Ok(number) if number.to_string() == value => Answer::Integer(number),
+            _ => Answer::Text(value),
+        }
+    }
+}
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{value}"),
+            Answer::Text(value) => f.write_str(value),
+        }
+    }
+}
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_text() == *other
+    }
+}
+
+/// Information about a single part of a solution.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PartInfo {
+    /// The number of the part, starting at 1. Anything after the two parts of the puzzle is a bonus part.
+    pub number: usize,
+    pub implemented: bool,
+    /// The names of the alternative implementations of the part.
+    pub alternatives: Vec<&'static str>,
+}
+
+/// The solution for a single day.
+///
+/// This is implemented for all days by collecting the parts registered using `#[aoc::solution(...)]`, see [`registered_solutions`].
+pub trait Solution: Send + Sync {
+    /// The identifier of the day, e.g. `day01`.
+    fn day(&self) -> &str;
+
+    /// The title of the puzzle, if known.
+    fn title(&self) -> Option<&'static str>;
+
+    /// All parts of the day, including the ones that haven't been implemented yet.
+    fn parts(&self) -> Vec<PartInfo>;
+
+    /// Run the given parts on the input.
+    fn run(
+        &self,
+        input: &Input,
+        parts: &[usize],
+        options: &RunnableOptions,
+    ) -> Result<Vec<PartRun>, String>;
+}
+
+/// The input of a day as parsed by its parser, with its type only known to the parts using it, so that days with different parsers can be stored together.
+pub type AnyParsed = Box<dyn Any + Send + Sync>;
+
+/// Erase the type of the result of a parser, see [`AnyParsed`].
+pub fn erase_parsed<P: Send + Sync + 'static>(parsed: P) -> Result<AnyParsed, String> {
+    Ok(Box::new(parsed))
+}
+
+/// Erase the type of the result of a parser that returns a [`Result`], see [`AnyParsed`].
+pub fn erase_try_parsed<P, E>(parsed: Result<P, E>) -> Result<AnyParsed, String>
+where
+    P: Send + Sync + 'static,
+    E: Display,
+{
+    parsed.map_err(|err| err.to_string()).and_then(erase_parsed)
+}
+
+/// Get the result of a parser back from an [`AnyParsed`]. The parser itself is only used to determine its type.
+pub fn downcast_parsed<P: 'static>(_parse: fn(&str) -> P, parsed: &AnyParsed) -> &P {
+    parsed
+        .downcast_ref()
+        .expect("The parsed input should have been produced by the parser of the part.")
+}
+
+/// Like [`downcast_parsed`], but for parsers that return a [`Result`].
+pub fn downcast_try_parsed<P: 'static, E>(
+    _parse: fn(&str) -> Result<P, E>,
+    parsed: &AnyParsed,
+) -> &P {
+    parsed
+        .downcast_ref()
+        .expect("The parsed input should have been produced by the parser of the part.")
+}
+
+/// Imported by `#[aoc::solution(...)]`, so that the aggregate runner can check that every day registers its parts.
+#[doc(hidden)]
+pub mod registered {
+    pub const PARTS_ARE_REGISTERED_USING_AOC_SOLUTION: () = ();
+}
+
+/// A function registered using `#[aoc::solution(...)]`.
+pub struct Registration {
+    pub day: usize,
+    pub part: usize,
+    /// The name of the alternative implementation, or `None` for the reference implementation of the part.
+    pub alternative: Option<&'static str>,
+    /// The file the function is defined in, which is where the title of the puzzle is taken from.
+    pub file: &'static str,
+    /// The name of the function, for use in error messages.
+    pub function: &'static str,
+    pub implementation: Implementation,
+}
+inventory::collect!(Registration);
+
+pub enum Implementation {
+    /// A part that takes the input as is.
+    Raw(fn(&str) -> Result<Answer, String>),
+    /// A part that takes the parsed input. The input is parsed once and then shared between the parts of the day, so they must all use the same parser.
+    Parsed {
+        /// The name of the parser, which is used to check that all parts of the day use the same one.
+        parser: &'static str,
+        parse: fn(&str) -> Result<AnyParsed, String>,
+        part: fn(&AnyParsed) -> Result<Answer, String>,
+    },
+}
+impl Implementation {
+    fn parser(&self) -> Option<&'static str> {
+        match self {
+            Implementation::Raw(_) => None,
+            Implementation::Parsed { parser, .. } => Some(parser),
+        }
+    }
+}
+
+/// A day made up of the registered parts.
+struct RegisteredDay {
+    name: String,
+    title: Option<&'static str>,
+    /// The registrations of the day, ordered by part and then by alternative.
+    registrations: Vec<&'static Registration>,
+}
+impl RegisteredDay {
+    /// Get the implementations of the given parts, using `to_part_fn` to get them from the registrations.
+    fn select_parts<I: ?Sized>(
+        &self,
+        numbers: &[usize],
+        to_part_fn: impl Fn(&Implementation) -> Option<PartFn<I>>,
+    ) -> Vec<Part<I>> {
+        numbers
+            .iter()
+            .map(|&number| {
+                let mut part = Part::new(number, None);
+                for registration in self.registrations.iter().filter(|r| r.part == number) {
+                    let Some(implementation) = to_part_fn(&registration.implementation) else {
+                        continue;
+                    };
+                    match registration.alternative {
+                        Some(name) => part.alternatives.push((name, implementation)),
+                        None => part.implementation = Some(implementation),
+                    }
+                }
+                part
+            })
+            .collect()
+    }
+}
+impl Solution for RegisteredDay {
+    fn day(&self) -> &str {
+        &self.name
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        self.title
+    }
+
+    fn parts(&self) -> Vec<PartInfo> {
+        // Puzzles always have two parts, even if they haven't been implemented yet.
+        let count = self
+            .registrations
+            .iter()
+            .map(|registration| registration.part)
+            .max()
+            .unwrap_or(0)
+            .max(2);
+        (1..=count)
+            .map(|number| {
+                let registrations = self.registrations.iter().filter(|r| r.part == number);
+                PartInfo {
+                    number,
+                    implemented: registrations.clone().any(|r| r.alternative.is_none()),
+                    alternatives: registrations.filter_map(|r| r.alternative).collect(),
+                }
+            })
+            .collect()
+    }
+
+    fn run(
+        &self,
+        input: &Input,
+        parts: &[usize],
+        options: &RunnableOptions,
+    ) -> Result<Vec<PartRun>, String> {
+        match self.registrations[0].implementation {
+            Implementation::Raw(_) => {
+                let parts = self.select_parts(parts, |implementation| match implementation {
+                    Implementation::Raw(part) => Some(Arc::new(*part) as PartFn<str>),
+                    Implementation::Parsed { .. } => None,
+                });
+                run_parts(input, &parts, options)
+            }
+            Implementation::Parsed { parse, .. } => {
+                let parts = self.select_parts(parts, |implementation| match implementation {
+                    Implementation::Parsed { part, .. } => Some(Arc::new(*part) as PartFn<_>),
+                    Implementation::Raw(_) => None,
+                });
+                run_parts_try_parsed(input, parse, &parts, options)
+            }
+        }
+    }
+}
+
+/// Collect the registered parts into a solution per day, ordered by day.
+///
+/// The titles of the puzzles are given by file, as they are taken from the doc comment at the top of the file.
+pub fn registered_solutions(
+    titles: &[(&str, &'static str)],
+) -> Result<Vec<Box<dyn Solution>>, String> {
+    let mut days: BTreeMap<usize, Vec<&'static Registration>> = BTreeMap::new();
+    for registration in inventory::iter::<Registration> {
+        days.entry(registration.day).or_default().push(registration);
+    }
+    days.into_iter()
+        .map(|(day, mut registrations)| {
+            registrations.sort_by_key(|r| (r.part, r.alternative));
+            let first = registrations[0];
+            for registration in &registrations {
+                if registration.file != first.file {
+                    return Err(format!(
+                        "{}: day {day} is already registered in {}.",
+                        registration.file, first.file
+                    ));
+                }
+                if registration.implementation.parser() != first.implementation.parser() {
+                    return Err(format!(
+                        "{}: {} and {} must use the same parser.",
+                        first.file, first.function, registration.function
+                    ));
+                }
+            }
+            let title = titles
+                .iter()
+                .find(|(file, _)| *file == first.file)
+                .map(|(_, title)| *title);
+            Ok(Box::new(RegisteredDay {
+                name: format!("day{day:02}"),
+                title,
+                registrations,
+            }) as Box<dyn Solution>)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    static REGISTRATIONS: [Registration; 2] = [
+        Registration {
+            day: 1,
+            part: 1,
+            alternative: None,
+            file: "src/bin/day01.rs",
+            function: "part1",
+            implementation: Implementation::Raw(|input| Ok(Answer::Integer(input.len() as i128))),
+        },
+        Registration {
+            day: 1,
+            part: 3,
+            alternative: Some("chars"),
+            file: "src/bin/day01.rs",
+            function: "part3_chars",
+            implementation: Implementation::Raw(|input| {
+                Ok(Answer::Integer(input.chars().count() as i128))
+            }),
+        },
+    ];
+
+    #[test]
+    fn registered_day() {
+        let day = RegisteredDay {
+            name: "day01".to_string(),
+            title: None,
+            registrations: REGISTRATIONS.iter().collect(),
+        };
+        assert_eq!(
+            day.parts(),
+            vec![
+                PartInfo {
+                    number: 1,
+                    implemented: true,
+                    alternatives: Vec::new(),
+                },
+                PartInfo {
+                    number: 2,
+                    implemented: false,
+                    alternatives: Vec::new(),
+                },
+                PartInfo {
+                    number: 3,
+                    implemented: false,
+                    alternatives: vec!["chars"],
+                },
+            ]
+        );
+
+        let runs = day
+            .run(
+                &Input::Text("föo".to_string()),
+                &[1, 3],
+                &RunnableOptions::default(),
+            )
+            .unwrap();
+        assert_eq!(runs[0].run.as_ref().unwrap().result, Answer::Integer(4));
+        assert_eq!(
+            runs[1].alternatives[0].1.as_ref().unwrap().result,
+            Answer::Integer(3)
+        );
+    }
+
+    #[test]
+    fn answer_of() {
+        assert_eq!(Answer::of(42u8), Answer::Integer(42));
+        assert_eq!(Answer::of(-7i64), Answer::Integer(-7));
+        assert_eq!(Answer::of(u128::MAX), Answer::Text(u128::MAX.to_string()));
+        assert_eq!(Answer::of("42"), Answer::Text("42".to_string()));
+        assert_eq!(Answer::of('x'), Answer::Text("x".to_string()));
+    }
+
+    #[test]
+    fn answer_from_string() {
+        assert_eq!(Answer::from("42".to_string()), Answer::Integer(42));
+        assert_eq!(Answer::from("-7".to_string()), Answer::Integer(-7));
+        assert_eq!(
+            Answer::from("007".to_string()),
+            Answer::Text("007".to_string())
+        );
+        assert_eq!(
+            Answer::from("RGZEHURK".to_string()),
+            Answer::Text("RGZEHURK".to_string())
+        );
+    }
+}
@@ -0,0 +1,191 @@
+use std::{
+    fs, thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    answers::get_answers_path,
+    colour::{Cyan, Green, Purple, Red},
+    report::Status,
+    runner::{get_output_path, Input, PartRun, RunnableRun},
+};
+
+/// The modification times of the files that affect the outcome of running an input.
+#[derive(Debug, PartialEq)]
+pub struct Snapshot(Vec<Option<SystemTime>>);
+impl Snapshot {
+    pub fn take(input: &Input) -> Self {
+        Self(
+            get_watched_paths(input)
+                .iter()
+                .map(|path| get_modified(path))
+                .collect(),
+        )
+    }
+}
+
+fn get_modified(path: &str) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Get the path of the source of the given day binary, which doesn't depend on the directory it is run from.
+pub fn get_source_path(name: &str) -> String {
+    format!("{}/src/bin/{name}.rs", env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Get the paths of the input file and the files the expected solutions can come from.
+fn get_watched_paths(input: &Input) -> Vec<String> {
+    let Some(path) = input.path() else {
+        return Vec::new();
+    };
+    vec![
+        path.to_string(),
+        get_answers_path(path).0,
+        get_output_path(path, 1),
+        get_output_path(path, 2),
+    ]
+}
+
+fn describe(run: &RunnableRun) -> String {
+    match run {
+        Ok(run) if run.result.as_text().contains('\n') => {
+            format!("<{} lines>", run.result.as_text().lines().count())
+        }
+        Ok(run) => run.result.to_string(),
+        Err(err) => err.to_string(),
+    }
+}
+
+fn paint_status(status: Status, text: String) -> String {
+    match status {
+        Status::Pass => Green.paint(text).to_string(),
+        Status::Fail | Status::ReturnedError | Status::Error => Red.paint(text).to_string(),
+        Status::Unverified | Status::Missing => text,
+    }
+}
+
+/// Describe how the outcome of a part changed between two runs in a single line.
+pub fn format_run_diff(previous: &RunnableRun, current: &RunnableRun) -> String {
+    let (previous_status, current_status) = (Status::of(previous), Status::of(current));
+    let current_formatted = paint_status(current_status, describe(current));
+    let mut line = if describe(previous) == describe(current) {
+        format!("{current_formatted} (unchanged)")
+    } else {
+        format!("{} → {current_formatted}", describe(previous))
+    };
+    if previous_status != current_status {
+        line = format!(
+            "{line} ({} → {})",
+            previous_status.as_str(),
+            paint_status(current_status, current_status.as_str().to_string()),
+        );
+    }
+    if let (Ok(previous), Ok(current)) = (previous, current) {
+        line = if previous.duration.is_zero() {
+            format!("{line} [{:?} → {:?}]", previous.duration, current.duration)
+        } else {
+            let change = current.duration.as_secs_f64() / previous.duration.as_secs_f64() - 1.0;
+            format!(
+                "{line} [{:?} → {:?}, {:+.0}%]",
+                previous.duration,
+                current.duration,
+                change * 100.0,
+            )
+        };
+    }
+    line
+}
+
+/// Poll the files of the given inputs for changes, running them again whenever this happens and printing how the outcome changed.
+///
+/// This never returns, the process has to be interrupted to stop watching.
+pub fn watch(
+    inputs: &[Input],
+    source: &str,
+    interval: Duration,
+    mut previous: Vec<Vec<PartRun>>,
+    run_input: impl Fn(&Input) -> Result<Vec<PartRun>, String>,
+) -> ! {
+    let mut snapshots: Vec<Snapshot> = inputs.iter().map(Snapshot::take).collect();
+    let mut source_modified = get_modified(source);
+    println!("Watching for changes...");
+    loop {
+        thread::sleep(interval);
+        // The running binary can't pick up changes to its own source, so all we can do is point this out.
+        let modified = get_modified(source);
+        if modified != source_modified {
+            source_modified = modified;
+            println!(
+                "{} changed, rebuild and restart to run the new version.",
+                Cyan.paint(source)
+            );
+        }
+        for (i, input) in inputs.iter().enumerate() {
+            let snapshot = Snapshot::take(input);
+            if snapshot == snapshots[i] {
+                continue;
+            }
+            snapshots[i] = snapshot;
+
+            println!(
+                "Change detected, running {}...",
+                Cyan.paint(input.to_string())
+            );
+            match run_input(input) {
+                Ok(runs) => {
+                    for (previous, current) in previous[i].iter().zip(&runs) {
+                        let diff = format_run_diff(&previous.run, &current.run);
+                        let name = format!("Part {}", current.part);
+                        println!("> {}: {diff}", Purple.paint(name));
+                    }
+                    previous[i] = runs;
+                }
+                Err(err) => println!("{}", Red.paint(err)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{
+        runner::{RunnableRunErr, RunnableRunOk},
+        solution::Answer,
+    };
+
+    fn run(result: &str, duration: Duration) -> RunnableRunOk {
+        RunnableRunOk {
+            result: Answer::from(result.to_string()),
+            solution: None,
+            duration,
+            stats: None,
+            parse_duration: None,
+            baseline: None,
+            memory: None,
+        }
+    }
+
+    #[test]
+    fn run_diff() {
+        let ms = Duration::from_millis;
+        assert_eq!(
+            format_run_diff(&Ok(run("5", ms(4))), &Ok(run("5", ms(2)))),
+            "5 (unchanged) [4ms → 2ms, -50%]"
+        );
+        assert_eq!(
+            format_run_diff(&Ok(run("5", ms(4))), &Ok(run("a\nb", ms(5)))),
+            "5 → <2 lines> [4ms → 5ms, +25%]"
+        );
+        assert_eq!(
+            format_run_diff(&Ok(run("5", ms(0))), &Ok(run("5", ms(1)))),
+            "5 (unchanged) [0ns → 1ms]"
+        );
+        assert_eq!(
+            format_run_diff(&Err(RunnableRunErr::Missing), &Ok(run("5", ms(4)))),
+            "Not implemented. → 5 (missing → unverified)"
+        );
+    }
+}